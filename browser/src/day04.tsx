@@ -0,0 +1,15 @@
+import { GridReplay, type GridFrames } from './gridReplay';
+
+import DATA from '@/data/output/puzzle04_frames.json';
+
+const replay = new GridReplay({
+  container: document.body,
+  frames: DATA as GridFrames,
+  colors: {
+    '.': '#181820',
+    '@': '#e6dcbe',
+    'x': '#dc3c32',
+  },
+  frameDelayMs: 300,
+});
+replay.play();
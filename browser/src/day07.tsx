@@ -0,0 +1,16 @@
+import { GridReplay, type GridFrames } from './gridReplay';
+
+import DATA from '@/data/output/puzzle07_frames.json';
+
+const replay = new GridReplay({
+  container: document.body,
+  frames: DATA as GridFrames,
+  colors: {
+    '.': '#181820',
+    'S': '#3cc850',
+    '^': '#ffffff',
+    '|': '#f0dc28',
+  },
+  frameDelayMs: 100,
+});
+replay.play();
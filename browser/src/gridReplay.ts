@@ -0,0 +1,163 @@
+/**
+ * Grid frames exported with `core_lib::GridRecording`: the first frame as rows
+ * and each next one as a list of changed cells.
+ */
+export interface GridFrames {
+    width: number;
+    height: number;
+    initial: string[];
+    deltas: Array<Array<[number, number, string]>>;
+}
+
+export interface GridReplayOptions {
+    container: HTMLElement;
+    frames: GridFrames;
+    /** Fill color for each cell character. */
+    colors: Record<string, string>;
+    defaultColor?: string;
+    frameDelayMs?: number;
+}
+
+export class GridReplay {
+    private readonly frames: GridFrames;
+    private readonly colors: Record<string, string>;
+    private readonly defaultColor: string;
+    private readonly frameDelayMs: number;
+
+    private readonly canvas: HTMLCanvasElement;
+    private readonly context: CanvasRenderingContext2D;
+    private readonly slider: HTMLInputElement;
+    private readonly playButton: HTMLButtonElement;
+    private readonly label: HTMLSpanElement;
+
+    /** Cells of the currently shown frame. */
+    private cells: string[][];
+    private frameIndex = 0;
+    private timer: number | undefined;
+
+    constructor(options: GridReplayOptions) {
+        const {container, frames, colors, defaultColor, frameDelayMs} = options;
+        this.frames = frames;
+        this.colors = colors;
+        this.defaultColor = defaultColor ?? '#000';
+        this.frameDelayMs = frameDelayMs ?? 200;
+        this.cells = frames.initial.map(row => Array.from(row));
+
+        const controls = document.createElement('div');
+        controls.className = 'replay-controls';
+        this.playButton = document.createElement('button');
+        this.playButton.addEventListener('click', () => this.togglePlay());
+        this.slider = document.createElement('input');
+        this.slider.type = 'range';
+        this.slider.min = '0';
+        this.slider.max = String(frames.deltas.length);
+        this.slider.value = '0';
+        this.slider.addEventListener('input', () => {
+            this.stop();
+            this.seek(Number(this.slider.value));
+        });
+        this.label = document.createElement('span');
+        controls.append(this.playButton, this.slider, this.label);
+
+        this.canvas = document.createElement('canvas');
+        this.canvas.className = 'replay-canvas';
+        const scale = Math.max(1, Math.floor(
+            Math.min(window.innerWidth / frames.width, (window.innerHeight - 60) / frames.height)
+        ));
+        this.canvas.width = frames.width * scale;
+        this.canvas.height = frames.height * scale;
+        const context = this.canvas.getContext('2d');
+        if (!context) {
+            throw new Error('Canvas 2D context is not supported');
+        }
+        this.context = context;
+        this.context.scale(scale, scale);
+
+        container.append(controls, this.canvas);
+        this.drawAll();
+        this.updateControls();
+    }
+
+    get frameCount(): number {
+        return this.frames.deltas.length + 1;
+    }
+
+    play(): void {
+        if (this.timer !== undefined) {
+            return;
+        }
+        if (this.frameIndex === this.frameCount - 1) {
+            this.seek(0);
+        }
+        this.timer = window.setInterval(() => {
+            if (this.frameIndex < this.frameCount - 1) {
+                this.seek(this.frameIndex + 1);
+            } else {
+                this.stop();
+            }
+        }, this.frameDelayMs);
+        this.updateControls();
+    }
+
+    stop(): void {
+        if (this.timer !== undefined) {
+            window.clearInterval(this.timer);
+            this.timer = undefined;
+        }
+        this.updateControls();
+    }
+
+    /** Shows the frame applying deltas forward (or replaying from the start when going back). */
+    seek(target: number): void {
+        if (target < this.frameIndex) {
+            this.cells = this.frames.initial.map(row => Array.from(row));
+            this.frameIndex = 0;
+            for (; this.frameIndex < target; this.frameIndex++) {
+                this.applyDelta(this.frames.deltas[this.frameIndex], false);
+            }
+            this.drawAll();
+        } else {
+            for (; this.frameIndex < target; this.frameIndex++) {
+                this.applyDelta(this.frames.deltas[this.frameIndex], true);
+            }
+        }
+        this.updateControls();
+    }
+
+    private togglePlay(): void {
+        if (this.timer === undefined) {
+            this.play();
+        } else {
+            this.stop();
+        }
+    }
+
+    private applyDelta(delta: Array<[number, number, string]>, draw: boolean): void {
+        for (const [x, y, cell] of delta) {
+            this.cells[y][x] = cell;
+            if (draw) {
+                this.drawCell(x, y);
+            }
+        }
+    }
+
+    private drawAll(): void {
+        for (let y = 0; y < this.frames.height; y++) {
+            for (let x = 0; x < this.frames.width; x++) {
+                this.drawCell(x, y);
+            }
+        }
+    }
+
+    private drawCell(x: number, y: number): void {
+        const cell = this.cells[y][x];
+        this.context.fillStyle = this.colors[cell] ?? this.defaultColor;
+        this.context.fillRect(x, y, 1, 1);
+    }
+
+    private updateControls(): void {
+        this.playButton.textContent = this.timer === undefined ? 'Play' : 'Pause';
+        this.slider.value = String(this.frameIndex);
+        this.label.textContent = `Frame ${this.frameIndex + 1} / ${this.frameCount}`;
+    }
+}
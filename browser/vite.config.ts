@@ -11,6 +11,8 @@ export default defineConfig({
         rollupOptions: {
             input: {
                 main: resolve(__dirname, 'index.html'),
+                day04: resolve(__dirname, 'day04.html'),
+                day07: resolve(__dirname, 'day07.html'),
                 day08: resolve(__dirname, 'day08.html'),
             },
             output: {
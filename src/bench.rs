@@ -0,0 +1,64 @@
+use crate::days::Day;
+use core_lib::{AocResult, BenchOptions, MeasureElapsed, StageStats, data_paths, read_input_file};
+use facet::Facet;
+
+#[derive(Facet)]
+#[facet(rename_all = "camelCase")]
+struct BenchReport {
+    warmup: usize,
+    samples: usize,
+    days: Vec<DayBench>,
+}
+
+#[derive(Facet)]
+struct DayBench {
+    day: u32,
+    title: String,
+    stages: Vec<StageStats>,
+}
+
+/// Benchmarks each stage (read, parse, part1, part2) of the selected days.
+pub fn bench_days(days: &[&Day], options: &BenchOptions, json: bool) -> AocResult<()> {
+    let mut report = BenchReport {
+        warmup: options.warmup,
+        samples: options.samples,
+        days: Vec::new(),
+    };
+
+    for day in days {
+        let input_path = data_paths().find(day.input_file())?;
+        let read = MeasureElapsed::benchmark(options, || read_input_file(&input_path))?;
+        let input = read_input_file(&input_path)?;
+
+        let mut stages = vec![StageStats { stage: "read".into(), elapsed: read }];
+        stages.extend(
+            (day.solution)(false).bench(&input, options)
+                .map_err(|err| err.with_file(&input_path))?
+        );
+
+        if !json {
+            print_day_bench(day, &stages);
+        }
+        report.days.push(DayBench { day: day.day, title: day.title.into(), stages });
+    }
+
+    if json {
+        println!("{}", facet_json::to_string_pretty(&report));
+    }
+    Ok(())
+}
+
+fn print_day_bench(day: &Day, stages: &[StageStats]) {
+    println!("Day {}: {}", day.day, day.title);
+    println!("  {:<6} {:>12} {:>12} {:>12} {:>12}", "stage", "min", "median", "mean", "stddev");
+    for StageStats { stage, elapsed } in stages {
+        println!(
+            "  {:<6} {:>12} {:>12} {:>12} {:>12}",
+            stage,
+            format!("{:.2?}", elapsed.min()),
+            format!("{:.2?}", elapsed.median()),
+            format!("{:.2?}", elapsed.mean()),
+            format!("{:.2?}", elapsed.stddev()),
+        );
+    }
+}
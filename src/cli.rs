@@ -0,0 +1,180 @@
+use std::path::PathBuf;
+
+pub const USAGE: &str = "\
+Usage: aoc [--data-dir <path>] <command>
+
+Commands:
+  run <day>|all [--part <1|2>]   Run both parts of a day (or every day);
+                                 downloads missing input if session is set
+  download <day>|all             Download and cache puzzle input
+  submit <day> --part <1|2>      Run a day part and submit its answer; skips
+                                 answers known to be wrong from history
+  verify [<day>|all] [--record]  Check answers against answers.json;
+                                 --record saves missing answers without asking
+  bench <day>|all [--samples <n>] [--warmup <n>] [--json]
+                                 Measure read, parse and part stages over
+                                 multiple runs; --json prints a JSON report
+  list                           List registered days
+
+Options:
+  --data-dir <path>   Data root directory with <year>/input/puzzleNN.txt files;
+                      defaults to $AOC_DATA_DIR, \"dataDir\" in aoc.json
+                      or ../advent-of-code-data
+
+Environment:
+  AOC_SESSION     Session cookie to download inputs (or \"session\" in aoc.json)
+  AOC_BASE_URL    Puzzle site URL (or \"baseUrl\" in aoc.json)";
+
+pub struct Cli {
+    pub command: Command,
+    pub data_dir: Option<PathBuf>,
+}
+
+pub enum Command {
+    Run { days: DaySelection, part: Option<u32> },
+    Download { days: DaySelection },
+    Submit { day: u32, part: u32 },
+    Verify { days: DaySelection, record: bool },
+    Bench { days: DaySelection, warmup: usize, samples: usize, json: bool },
+    List,
+}
+
+pub enum DaySelection {
+    All,
+    Single(u32),
+}
+
+impl Cli {
+    pub fn parse(args: &[String]) -> Result<Cli, String> {
+        let mut data_dir = None;
+        let mut rest = Vec::new();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            if arg == "--data-dir" {
+                let value = args.next().ok_or("Missing value for --data-dir")?;
+                data_dir = Some(PathBuf::from(value));
+            } else {
+                rest.push(arg.clone());
+            }
+        }
+        let command = Command::parse(&rest)?;
+        Ok(Cli { command, data_dir })
+    }
+}
+
+impl Command {
+    pub fn parse(args: &[String]) -> Result<Command, String> {
+        let mut args = args.iter().map(|arg| arg.as_str()).peekable();
+        match args.next() {
+            Some("run") => {
+                let days = match args.next() {
+                    Some(day) => DaySelection::parse(day)?,
+                    None => Err("Missing day to run")?,
+                };
+
+                let mut part = None;
+                while let Some(arg) = args.next() {
+                    match arg {
+                        "--part" | "-p" => part = Some(parse_part(args.next())?),
+                        _ => Err(format!("Unexpected argument: {arg}"))?,
+                    }
+                }
+
+                Ok(Command::Run { days, part })
+            }
+            Some("download") => {
+                let days = match args.next() {
+                    Some(day) => DaySelection::parse(day)?,
+                    None => Err("Missing day to download")?,
+                };
+                if let Some(arg) = args.next() {
+                    Err(format!("Unexpected argument: {arg}"))?;
+                }
+                Ok(Command::Download { days })
+            }
+            Some("submit") => {
+                let day = match args.next() {
+                    Some(day) => day.parse().map_err(|_| format!("Invalid day: {day}"))?,
+                    None => Err("Missing day to submit")?,
+                };
+
+                let mut part = None;
+                while let Some(arg) = args.next() {
+                    match arg {
+                        "--part" | "-p" => part = Some(parse_part(args.next())?),
+                        _ => Err(format!("Unexpected argument: {arg}"))?,
+                    }
+                }
+                let part = part.ok_or("Missing --part to submit")?;
+
+                Ok(Command::Submit { day, part })
+            }
+            Some("verify") => {
+                let days = match args.next_if(|arg| !arg.starts_with("-")) {
+                    Some(day) => DaySelection::parse(day)?,
+                    None => DaySelection::All,
+                };
+
+                let mut record = false;
+                for arg in args {
+                    match arg {
+                        "--record" => record = true,
+                        _ => Err(format!("Unexpected argument: {arg}"))?,
+                    }
+                }
+
+                Ok(Command::Verify { days, record })
+            }
+            Some("bench") => {
+                let days = match args.next() {
+                    Some(day) => DaySelection::parse(day)?,
+                    None => Err("Missing day to benchmark")?,
+                };
+
+                let mut warmup = 3;
+                let mut samples = 20;
+                let mut json = false;
+                while let Some(arg) = args.next() {
+                    match arg {
+                        "--samples" => samples = parse_count(arg, args.next())?,
+                        "--warmup" => warmup = parse_count(arg, args.next())?,
+                        "--json" => json = true,
+                        _ => Err(format!("Unexpected argument: {arg}"))?,
+                    }
+                }
+                if samples == 0 {
+                    Err("Benchmark requires at least one sample")?;
+                }
+
+                Ok(Command::Bench { days, warmup, samples, json })
+            }
+            Some("list") => Ok(Command::List),
+            Some(other) => Err(format!("Unknown command: {other}")),
+            None => Err("Missing command".into()),
+        }
+    }
+}
+
+impl DaySelection {
+    fn parse(arg: &str) -> Result<DaySelection, String> {
+        match arg {
+            "all" => Ok(DaySelection::All),
+            day => Ok(DaySelection::Single(
+                day.parse().map_err(|_| format!("Invalid day: {day}"))?
+            )),
+        }
+    }
+}
+
+fn parse_count(name: &str, value: Option<&str>) -> Result<usize, String> {
+    let value = value.ok_or_else(|| format!("Missing value for {name}"))?;
+    value.parse().map_err(|_| format!("Invalid {name} value: {value}"))
+}
+
+fn parse_part(value: Option<&str>) -> Result<u32, String> {
+    let value = value.ok_or("Missing value for --part")?;
+    value.parse()
+        .ok()
+        .filter(|&p| p == 1 || p == 2)
+        .ok_or_else(|| format!("Invalid part: {value}"))
+}
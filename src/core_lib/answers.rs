@@ -0,0 +1,90 @@
+use crate::{AocError, AocResult, read_json_file};
+use facet::Facet;
+use std::fs::write;
+use std::path::{Path, PathBuf};
+
+/// Registry of known correct answers keyed by day, part and input fingerprint.
+pub struct AnswerRegistry {
+    path: PathBuf,
+    entries: Vec<AnswerEntry>,
+}
+
+#[derive(Facet, Clone)]
+pub struct AnswerEntry {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub answer: String,
+}
+
+#[derive(Facet)]
+struct AnswersFile {
+    answers: Vec<AnswerEntry>,
+}
+
+pub enum AnswerCheck {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl AnswerRegistry {
+    /// Loads answers from a JSON file or starts an empty registry if it does not exist.
+    pub fn load(path: impl AsRef<Path>) -> AocResult<AnswerRegistry> {
+        let path = path.as_ref().to_path_buf();
+        let entries = if path.exists() {
+            let file: AnswersFile = read_json_file(&path)?;
+            file.answers
+        } else {
+            Vec::new()
+        };
+        Ok(AnswerRegistry { path, entries })
+    }
+
+    pub fn save(&self) -> AocResult<()> {
+        let mut answers = self.entries.clone();
+        answers.sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+        let content = facet_json::to_string_pretty(&AnswersFile { answers }) + "\n";
+        write(&self.path, content).map_err(|err| AocError::io(&self.path, err))
+    }
+
+    pub fn get(&self, day: u32, part: u32, fingerprint: &str) -> Option<&str> {
+        self.entries.iter()
+            .find(|e| e.day == day && e.part == part && e.input == fingerprint)
+            .map(|e| e.answer.as_str())
+    }
+
+    pub fn check(&self, day: u32, part: u32, fingerprint: &str, answer: &str) -> AnswerCheck {
+        match self.get(day, part, fingerprint) {
+            Some(expected) if expected == answer => AnswerCheck::Pass,
+            Some(expected) => AnswerCheck::Fail { expected: expected.into() },
+            None => AnswerCheck::Missing,
+        }
+    }
+
+    /// Records the answer, replacing an existing one for the same key.
+    pub fn record(&mut self, day: u32, part: u32, fingerprint: &str, answer: &str) {
+        self.entries.retain(|e| !(e.day == day && e.part == part && e.input == fingerprint));
+        self.entries.push(AnswerEntry {
+            day,
+            part,
+            input: fingerprint.into(),
+            answer: answer.into(),
+        });
+    }
+}
+
+/// Computes stable input fingerprint (64-bit FNV-1a hash as hex string).
+///
+/// Line endings are normalized to make the fingerprint independent of checkout settings.
+pub fn input_fingerprint(input: &str) -> String {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut hash = OFFSET_BASIS;
+    for byte in input.bytes().filter(|&b| b != b'\r') {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(PRIME);
+    }
+    format!("{hash:016x}")
+}
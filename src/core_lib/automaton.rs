@@ -0,0 +1,148 @@
+use crate::Grid;
+use std::mem::swap;
+
+/// Cellular automaton which computes each cell of the next generation with a `rule`
+/// from the current generation, e.g. for Game of Life:
+/// ```
+/// # use core_lib::{Automaton, Grid, MOORE_NEIGHBORS};
+/// let blinker = Grid::from([[false, true, false]; 3]);
+/// let mut life = Automaton::new(blinker, |grid: &Grid<bool>, at, &alive| {
+///     let around = grid.count_neighbors(at, MOORE_NEIGHBORS, |&v| v);
+///     around == 3 || (alive && around == 2)
+/// });
+/// assert_eq!(life.step(), 4);
+/// assert_eq!(life.grid().row(1), Some(&[true, true, true][..]));
+/// ```
+pub struct Automaton<T, R> {
+    current: Grid<T>,
+    next: Grid<T>,
+    rule: R,
+    generation: usize,
+}
+
+/// Generation reported to the callback after each step.
+pub struct Generation<'a, T> {
+    /// Generation number starting from 1 for the first computed one.
+    pub index: usize,
+    pub grid: &'a Grid<T>,
+    /// Count of cells changed from the previous generation.
+    pub changed: usize,
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: Clone + PartialEq,
+    R: FnMut(&Grid<T>, (i32, i32), &T) -> T,
+{
+    pub fn new(grid: Grid<T>, rule: R) -> Automaton<T, R> {
+        Automaton {
+            next: grid.clone(),
+            current: grid,
+            rule,
+            generation: 0,
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.current
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.current
+    }
+
+    /// Number of computed generations.
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Computes the next generation and returns count of changed cells.
+    pub fn step(&mut self) -> usize {
+        let mut changed = 0;
+        for (at, cell) in self.next.iter_mut() {
+            let current = &self.current[at];
+            let value = (self.rule)(&self.current, at, current);
+            if value != *current {
+                changed += 1;
+            }
+            *cell = value;
+        }
+        swap(&mut self.current, &mut self.next);
+        self.generation += 1;
+        return changed;
+    }
+
+    /// Computes `n` generations calling `on_generation` after each one.
+    ///
+    /// Returns changed cell count for each generation.
+    pub fn run_n<E>(
+        &mut self,
+        n: usize,
+        mut on_generation: impl FnMut(&Generation<T>) -> Result<(), E>
+    ) -> Result<Vec<usize>, E> {
+        let mut changes = Vec::with_capacity(n);
+        for _ in 0..n {
+            let changed = self.step();
+            on_generation(&self.last_generation(changed))?;
+            changes.push(changed);
+        }
+        Ok(changes)
+    }
+
+    /// Computes generations until nothing changes calling `on_generation` after each
+    /// one with changes (i.e. not for the final stable one).
+    ///
+    /// Returns changed cell count for each generation with changes.
+    pub fn run_until_stable<E>(
+        &mut self,
+        mut on_generation: impl FnMut(&Generation<T>) -> Result<(), E>
+    ) -> Result<Vec<usize>, E> {
+        let mut changes = Vec::new();
+        loop {
+            let changed = self.step();
+            if changed == 0 {
+                break;
+            }
+            on_generation(&self.last_generation(changed))?;
+            changes.push(changed);
+        }
+        Ok(changes)
+    }
+
+    fn last_generation(&self, changed: usize) -> Generation<'_, T> {
+        Generation { index: self.generation, grid: &self.current, changed }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ORTHOGONAL_NEIGHBORS;
+
+    #[test]
+    fn test_run_until_stable() {
+        // Fire spreads to orthogonal neighbors then burns out
+        let grid = Grid::from([
+            ['.', '.', '#'],
+            ['.', '#', '#'],
+            ['*', '#', '.'],
+        ]);
+        let mut fire = Automaton::new(grid, |grid: &Grid<char>, at, &cell| match cell {
+            '*' => '_',
+            '#' if grid.count_neighbors(at, ORTHOGONAL_NEIGHBORS, |&v| v == '*') > 0 => '*',
+            _ => cell,
+        });
+
+        let mut generations = Vec::new();
+        let changes = fire.run_until_stable(|generation| {
+            generations.push(generation.index);
+            Ok::<_, ()>(())
+        }).unwrap();
+        assert_eq!(changes, vec![2, 2, 2, 2, 1]);
+        assert_eq!(generations, vec![1, 2, 3, 4, 5]);
+        assert_eq!(fire.generation(), 6);
+        assert_eq!(fire.grid().iter().filter(|(_, c)| **c == '_').count(), 5);
+
+        assert_eq!(fire.run_n(2, |_| Ok::<_, ()>(())), Ok(vec![0, 0]));
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{AocConfig, AocError, AocResult, DataPaths, input_file};
+use std::fs::{create_dir_all, read_to_string, rename, write};
+use std::path::{Path, PathBuf};
+use std::thread::sleep;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Environment variable with the session cookie value.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Environment variable to override the puzzle site URL (e.g. for a local mock server).
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Minimal interval between consecutive requests to the puzzle site.
+pub const DEFAULT_REQUEST_INTERVAL: Duration = Duration::from_secs(5);
+
+const USER_AGENT: &str = "github.com/AlexeyMz/advent-of-code-2025";
+
+/// File in the data directory with the last request time.
+const LAST_REQUEST_FILE: &str = ".last_request";
+
+pub struct ClientConfig {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub request_interval: Duration,
+}
+
+impl ClientConfig {
+    /// Reads settings from (in order of precedence):
+    ///   - `AOC_SESSION` and `AOC_BASE_URL` environment variables;
+    ///   - `session` and `baseUrl` in `aoc.json` config file.
+    ///
+    /// Returns an HTTP error if the base URL is not an `http(s)://` one.
+    pub fn resolve() -> AocResult<ClientConfig> {
+        let config = AocConfig::load()?
+            .map(|(_, config)| config)
+            .unwrap_or_default();
+        let from_env = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+        let base_url = from_env(BASE_URL_ENV)
+            .or(config.base_url)
+            .unwrap_or_else(|| DEFAULT_BASE_URL.into());
+        if !base_url.starts_with("http://") && !base_url.starts_with("https://") {
+            Err(AocError::Http {
+                url: base_url.clone(),
+                message: format!(
+                    "Base URL must start with http:// or https://: set {BASE_URL_ENV} or \"baseUrl\" in aoc.json"
+                ),
+            })?;
+        }
+        Ok(ClientConfig {
+            base_url,
+            session: from_env(SESSION_ENV).or(config.session),
+            request_interval: DEFAULT_REQUEST_INTERVAL,
+        })
+    }
+}
+
+/// HTTP client for the puzzle site which caches inputs in the data directory
+/// and throttles requests (including ones from previous runs).
+pub struct AocClient {
+    config: ClientConfig,
+    paths: DataPaths,
+    agent: ureq::Agent,
+}
+
+impl AocClient {
+    pub fn new(config: ClientConfig, paths: DataPaths) -> AocClient {
+        let agent = ureq::Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .http_status_as_error(false)
+            .build()
+            .into();
+        AocClient { config, paths, agent }
+    }
+
+    pub fn has_session(&self) -> bool {
+        self.config.session.is_some()
+    }
+
+    /// Returns path to the day input, downloading it into the data directory
+    /// if it is not found in any data root.
+    ///
+    /// Cached input is never downloaded again.
+    pub fn fetch_input(&self, day: u32) -> AocResult<PathBuf> {
+        let input_file = input_file(day);
+        if let Ok(cached) = self.paths.find(&input_file) {
+            return Ok(cached);
+        }
+
+        let url = self.url(&format!("{}/day/{day}/input", self.year()));
+        let cookie = self.session_cookie(&url)?;
+        self.throttle()?;
+        let response = self.agent.get(&url)
+            .header("Cookie", &cookie)
+            .call();
+        let content = read_response(&url, response)?;
+
+        let path = self.paths.get(&input_file);
+        write_file_atomic(&path, &content)?;
+        Ok(path)
+    }
+
+    pub fn year(&self) -> u32 {
+        self.paths.year()
+    }
+
+    /// Posts form fields to a site path (relative to the base URL) and returns the page.
+    pub fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> AocResult<String> {
+        let url = self.url(path);
+        let cookie = self.session_cookie(&url)?;
+        self.throttle()?;
+        let response = self.agent.post(&url)
+            .header("Cookie", &cookie)
+            .send_form(fields.iter().copied());
+        read_response(&url, response)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{path}", self.config.base_url.trim_end_matches('/'))
+    }
+
+    fn session_cookie(&self, url: &str) -> AocResult<String> {
+        match &self.config.session {
+            Some(session) => Ok(format!("session={session}")),
+            None => Err(AocError::Http {
+                url: url.into(),
+                message: format!(
+                    "Session token is not configured: set {SESSION_ENV} or \"session\" in aoc.json"
+                ),
+            }),
+        }
+    }
+
+    /// Waits until the request interval has passed since the last request.
+    fn throttle(&self) -> AocResult<()> {
+        let path = self.paths.get(LAST_REQUEST_FILE);
+        let last_request = read_to_string(&path).ok()
+            .and_then(|content| content.trim().parse::<u64>().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(last_request) = last_request
+            && let Ok(elapsed) = SystemTime::now().duration_since(last_request)
+            && elapsed < self.config.request_interval
+        {
+            sleep(self.config.request_interval - elapsed);
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        write_file_atomic(&path, &now.as_millis().to_string())
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>
+) -> AocResult<String> {
+    let http_error = |message: String| AocError::Http { url: url.into(), message };
+    let mut response = response.map_err(|err| http_error(err.to_string()))?;
+    let body = response.body_mut().read_to_string()
+        .map_err(|err| http_error(err.to_string()))?;
+    let status = response.status();
+    if !status.is_success() {
+        // Include plain text explanation (e.g. "Please log in") but skip HTML pages
+        let details = body.lines().next().unwrap_or("").trim();
+        let message = if details.is_empty() || details.starts_with('<') {
+            format!("HTTP {status}")
+        } else {
+            format!("HTTP {status}: {details}")
+        };
+        Err(http_error(message))?;
+    }
+    Ok(body)
+}
+
+/// Writes into a temporary file then moves it into place
+/// to avoid leaving partially written files.
+fn write_file_atomic(path: &Path, content: &str) -> AocResult<()> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).map_err(|err| AocError::io(parent, err))?;
+    }
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    write(&temp_path, content).map_err(|err| AocError::io(&temp_path, err))?;
+    rename(&temp_path, path).map_err(|err| AocError::io(path, err))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::DataRoot;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::ops::Deref;
+    use std::thread::JoinHandle;
+
+    /// Starts a server which replies with `responses` to consecutive requests
+    /// and returns its base URL and received requests when all are handled.
+    pub(crate) fn mock_server(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                ).unwrap();
+            }
+            return requests;
+        });
+        (base_url, handle)
+    }
+
+    /// Client with a temporary data directory which is removed when dropped.
+    pub(crate) struct TestClient {
+        client: AocClient,
+        root: PathBuf,
+    }
+
+    impl Deref for TestClient {
+        type Target = AocClient;
+
+        fn deref(&self) -> &AocClient {
+            &self.client
+        }
+    }
+
+    impl Drop for TestClient {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.root);
+        }
+    }
+
+    pub(crate) fn test_client(name: &str, base_url: String) -> TestClient {
+        let root = std::env::temp_dir()
+            .join(format!("aoc-{name}-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        let paths = DataPaths::from_roots(2025, vec![DataRoot { path: root.clone(), source: "test".into() }]);
+        let config = ClientConfig {
+            base_url,
+            session: Some("secret".into()),
+            request_interval: Duration::ZERO,
+        };
+        TestClient { client: AocClient::new(config, paths), root }
+    }
+
+    #[test]
+    fn test_fetch_input_once() {
+        let (base_url, server) = mock_server(vec![(200, "1,2,3\n")]);
+        let client = test_client("fetch-input", base_url);
+
+        let path = client.fetch_input(3).unwrap();
+        assert!(path.ends_with("2025/input/puzzle03.txt"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "1,2,3\n");
+
+        assert_eq!(client.fetch_input(3).unwrap(), path);
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2025/day/3/input "));
+        assert!(requests[0].contains("session=secret"));
+    }
+
+    #[test]
+    fn test_fetch_input_error() {
+        let (base_url, server) = mock_server(vec![(404, "Not found\n")]);
+        let client = test_client("fetch-error", base_url);
+
+        let err = client.fetch_input(20).unwrap_err();
+        assert!(err.to_string().contains("HTTP 404"), "{err}");
+        server.join().unwrap();
+        assert!(client.paths.find(input_file(20)).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+pub type AocResult<T> = Result<T, AocError>;
+
+#[derive(Debug)]
+pub enum AocError {
+    Io { path: PathBuf, source: std::io::Error },
+    /// I/O failure on a reader or writer not backed by a known file.
+    Stream(std::io::Error),
+    Parse(ParseError),
+    Http { url: String, message: String },
+    /// Invalid request from the command line, e.g. a day which is not implemented.
+    Usage(String),
+    Invariant(String),
+}
+
+/// Input parse failure with source location (1-based line and column).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub message: String,
+}
+
+impl AocError {
+    pub fn io(path: impl AsRef<Path>, source: std::io::Error) -> AocError {
+        AocError::Io { path: path.as_ref().to_path_buf(), source }
+    }
+
+    /// Attaches source file path to a parse error.
+    pub fn with_file(self, file: impl AsRef<Path>) -> AocError {
+        match self {
+            AocError::Parse(err) => AocError::Parse(err.with_file(file)),
+            other => other,
+        }
+    }
+}
+
+impl ParseError {
+    /// Creates an error pointing at `fragment` which should be a sub-slice of `input`
+    /// (e.g. a line or a token from it), otherwise the first occurrence of it is used.
+    pub fn at(input: &str, fragment: &str, message: impl Into<String>) -> ParseError {
+        let input_start = input.as_ptr() as usize;
+        let fragment_start = fragment.as_ptr() as usize;
+        let offset = if fragment_start >= input_start && fragment_start <= input_start + input.len() {
+            fragment_start - input_start
+        } else {
+            input.find(fragment).unwrap_or(0)
+        };
+        ParseError::at_offset(input, offset, message)
+    }
+
+    /// Creates an error pointing at byte `offset` in `input`.
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> ParseError {
+        let mut offset = offset.min(input.len());
+        while !input.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let line_start = input[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+        let line_end = input[offset..].find('\n').map(|i| offset + i).unwrap_or(input.len());
+        ParseError {
+            file: None,
+            line: input[..line_start].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end].trim_end_matches('\r').into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn with_file(self, file: impl AsRef<Path>) -> ParseError {
+        ParseError { file: Some(file.as_ref().to_path_buf()), ..self }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io { path, source } => write!(f, "{}: {source}", path.display()),
+            AocError::Stream(source) => write!(f, "{source}"),
+            AocError::Parse(err) => write!(f, "{err}"),
+            AocError::Http { url, message } => write!(f, "{url}: {message}"),
+            AocError::Usage(message) => write!(f, "{message}"),
+            AocError::Invariant(message) => write!(f, "{message}"),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.file {
+            Some(file) => write!(f, "{}:{}:{}: ", file.display(), self.line, self.column)?,
+            None => write!(f, "line {}, column {}: ", self.line, self.column)?,
+        }
+        let gutter = " ".repeat(self.line.to_string().len());
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(f, "{gutter} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            AocError::Stream(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for AocError {
+    fn from(err: ParseError) -> AocError {
+        AocError::Parse(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "L10\nR5\nLx7\n";
+        let line = input.lines().nth(2).unwrap();
+        let err = ParseError::at(input, &line[1..], "Invalid count");
+        assert_eq!((err.line, err.column), (3, 2));
+        assert_eq!(err.snippet, "Lx7");
+
+        let err = ParseError::at_offset("ab\nöx", 5, "Invalid");
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+}
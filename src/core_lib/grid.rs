@@ -1,5 +1,23 @@
-use std::io::{LineWriter, Write};
+use crate::{AocError, AocResult, GridView, ParseError, Point2, Vec2};
+use std::fmt::Display;
+use std::io::{BufRead, LineWriter, Write};
+use std::iter::repeat_n;
+use std::ops::{Index, IndexMut};
 
+/// Offsets to orthogonal neighbors: up, right, down, left.
+pub const ORTHOGONAL_NEIGHBORS: &[(i32, i32)] = &[(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets to diagonal neighbors: up-right, down-right, down-left, up-left.
+pub const DIAGONAL_NEIGHBORS: &[(i32, i32)] = &[(1, -1), (1, 1), (-1, 1), (-1, -1)];
+
+/// Offsets to all 8 surrounding cells (Moore neighborhood) in row-major order.
+pub const MOORE_NEIGHBORS: &[(i32, i32)] = &[
+    (-1, -1), (0, -1), (1, -1),
+    (-1, 0), (1, 0),
+    (-1, 1), (0, 1), (1, 1),
+];
+
+#[derive(Debug)]
 pub struct Grid<T> {
     data: Vec<T>,
     width: i32,
@@ -14,17 +32,6 @@ impl<T> Grid<T> {
     pub fn height(&self) -> i32 {
         self.height
     }
-}
-
-impl<T: Clone> Grid<T> {
-    pub fn new(width: i32, height: i32, filler: T) -> Grid<T> {
-        let size: usize = (width * height).try_into().unwrap();
-        Grid {
-            data: vec![filler; size],
-            width,
-            height,
-        }
-    }
 
     pub fn from<const WIDTH: usize, const HEIGHT: usize>(data: [[T; WIDTH]; HEIGHT]) -> Grid<T> {
         Grid {
@@ -34,7 +41,16 @@ impl<T: Clone> Grid<T> {
         }
     }
 
-    pub fn map<U: Clone>(&self, mapper: impl FnMut(&T) -> U) -> Grid<U> {
+    pub(crate) fn from_cells(data: Vec<T>, width: i32, height: i32) -> Grid<T> {
+        assert_eq!(data.len(), (width * height) as usize, "Grid: invalid cell count");
+        Grid { data, width, height }
+    }
+
+    pub(crate) fn into_cells(self) -> Vec<T> {
+        self.data
+    }
+
+    pub fn map<U>(&self, mapper: impl FnMut(&T) -> U) -> Grid<U> {
         Grid {
             data: self.data.iter().map(mapper).collect(),
             width: self.width,
@@ -42,29 +58,42 @@ impl<T: Clone> Grid<T> {
         }
     }
 
-    pub fn valid(&self, (x, y): (i32, i32)) -> bool {
+    pub fn valid(&self, at: impl Into<Point2>) -> bool {
+        let Point2 { x, y } = at.into();
         x >= 0 && x < self.width &&
         y >= 0 && y < self.height
     }
 
-    pub fn get(&self, at: (i32, i32)) -> Option<T> {
+    fn index_of(&self, at: impl Into<Point2>) -> Option<usize> {
+        let at = at.into();
         if self.valid(at) {
-            let index: usize = (at.1 * self.width + at.0).try_into().unwrap();
-            Some(self.data[index].clone())
+            Some((at.y * self.width + at.x) as usize)
         } else {
             None
         }
     }
 
-    pub fn set(&mut self, at: (i32, i32), value: T) {
+    fn position_of(&self, index: usize) -> (i32, i32) {
+        let index = index as i32;
+        (index % self.width, index / self.width)
+    }
+
+    pub fn get_ref(&self, at: impl Into<Point2>) -> Option<&T> {
+        self.index_of(at).map(|index| &self.data[index])
+    }
+
+    pub fn get_mut(&mut self, at: impl Into<Point2>) -> Option<&mut T> {
+        self.index_of(at).map(|index| &mut self.data[index])
+    }
+
+    pub fn set(&mut self, at: impl Into<Point2>, value: T) {
         if !self.try_set(at, value) {
             panic!("Grid: trying to set out of bounds")
         }
     }
 
-    pub fn try_set(&mut self, at: (i32, i32), value: T) -> bool {
-        if self.valid(at) {
-            let index: usize = (at.1 * self.width + at.0).try_into().unwrap();
+    pub fn try_set(&mut self, at: impl Into<Point2>, value: T) -> bool {
+        if let Some(index) = self.index_of(at) {
             self.data[index] = value;
             return true;
         } else {
@@ -72,6 +101,259 @@ impl<T: Clone> Grid<T> {
         }
     }
 
+    /// Iterates over `(position, value)` of all cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.data.iter()
+            .enumerate()
+            .map(|(index, value)| (self.position_of(index), value))
+    }
+
+    /// Iterates over `(position, value)` of all cells in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((i32, i32), &mut T)> {
+        let width = self.width;
+        self.data.iter_mut()
+            .enumerate()
+            .map(move |(index, value)| {
+                let index = index as i32;
+                ((index % width, index / width), value)
+            })
+    }
+
+    /// Iterates over all positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (i32, i32)> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Iterates over positions with values matching the predicate in row-major order.
+    pub fn positions_where<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a
+    ) -> impl Iterator<Item = (i32, i32)> + 'a {
+        self.iter().filter(move |(_, value)| predicate(value)).map(|(at, _)| at)
+    }
+
+    pub fn row(&self, y: i32) -> Option<&[T]> {
+        if y >= 0 && y < self.height {
+            let start = (y * self.width) as usize;
+            Some(&self.data[start..start + self.width as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).filter_map(|y| self.row(y))
+    }
+
+    /// Iterates over column cells from top to bottom (empty if out of bounds).
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> {
+        let height = if x >= 0 && x < self.width { self.height } else { 0 };
+        (0..height).map(move |y| &self[(x, y)])
+    }
+
+    /// Iterates over columns from left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Returns zero-copy view of a rectangle with the top-left corner at `at`
+    /// or `None` if it does not fit into the grid.
+    pub fn view(&self, at: impl Into<Point2>, width: i32, height: i32) -> Option<GridView<'_, T>> {
+        GridView::new(self, at.into(), width, height)
+    }
+
+    /// Returns view of the whole grid.
+    pub fn as_view(&self) -> GridView<'_, T> {
+        GridView::new(self, Point2::new(0, 0), self.width, self.height).unwrap()
+    }
+
+    /// Iterates over `(position, value)` of cells at `stencil` offsets from `at`,
+    /// skipping ones outside of the grid.
+    ///
+    /// Stencil is a slice of offsets, e.g. [`MOORE_NEIGHBORS`] or [`Direction::ALL`](crate::Direction::ALL).
+    /// Counting neighbors is a one-liner, e.g.:
+    /// `grid.neighbors(at, MOORE_NEIGHBORS).filter(|(_, v)| **v == '@').count()`.
+    pub fn neighbors<'a, S: Copy + Into<Vec2>>(
+        &'a self,
+        at: impl Into<Point2>,
+        stencil: &'a [S]
+    ) -> impl Iterator<Item = ((i32, i32), &'a T)> + 'a {
+        let at: Point2 = at.into();
+        stencil.iter().filter_map(move |&offset| {
+            let next = at + offset.into();
+            self.index_of(next).map(|index| (next.into(), &self.data[index]))
+        })
+    }
+
+    /// Same as [`Grid::neighbors`] but wraps around the edges (as on a torus).
+    pub fn neighbors_wrapping<'a, S: Copy + Into<Vec2>>(
+        &'a self,
+        at: impl Into<Point2>,
+        stencil: &'a [S]
+    ) -> impl Iterator<Item = ((i32, i32), &'a T)> + 'a {
+        let at: Point2 = at.into();
+        stencil.iter().filter_map(move |&offset| {
+            if self.width <= 0 || self.height <= 0 {
+                return None;
+            }
+            let next = at + offset.into();
+            let next = (next.x.rem_euclid(self.width), next.y.rem_euclid(self.height));
+            self.index_of(next).map(|index| (next, &self.data[index]))
+        })
+    }
+
+    /// Counts cells at `stencil` offsets from `at` which match the predicate.
+    pub fn count_neighbors<S: Copy + Into<Vec2>>(
+        &self,
+        at: impl Into<Point2>,
+        stencil: &[S],
+        mut predicate: impl FnMut(&T) -> bool
+    ) -> usize {
+        self.neighbors(at, stencil).filter(|(_, value)| predicate(value)).count()
+    }
+
+    /// Parses grid from non-empty lines of text, mapping each character into a cell.
+    ///
+    /// Fails at the first cell rejected by the `mapper` or a row with different length.
+    pub fn parse<E: Display>(
+        input: &str,
+        mapper: impl FnMut(char) -> Result<T, E>
+    ) -> Result<Grid<T>, ParseError> {
+        let rows = Grid::parse_rows(input, mapper)?;
+        let width = rows.first().map_or(0, |(_, row)| row.len());
+        let height = rows.len();
+        let mut data = Vec::with_capacity(width * rows.len());
+        for (line, row) in rows {
+            if row.len() != width {
+                Err(ParseError::at(
+                    input,
+                    line,
+                    format!("Grid row has length {}, expected {width}", row.len())
+                ))?;
+            }
+            data.extend(row);
+        }
+        return Ok(Grid::from_cells(data, width as i32, height as i32));
+    }
+
+    fn parse_rows<E: Display>(
+        input: &str,
+        mut mapper: impl FnMut(char) -> Result<T, E>
+    ) -> Result<Vec<(&str, Vec<T>)>, ParseError> {
+        let mut rows = Vec::new();
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            let mut row = Vec::new();
+            for (offset, ch) in line.char_indices() {
+                let cell = mapper(ch).map_err(|err| ParseError::at(
+                    input,
+                    &line[offset..],
+                    format!("Invalid grid cell: {err}")
+                ))?;
+                row.push(cell);
+            }
+            rows.push((line, row));
+        }
+        return Ok(rows);
+    }
+
+    /// Reads the whole input then parses grid in the same way as [`Grid::parse`].
+    pub fn read<E: Display>(
+        mut reader: impl BufRead,
+        mapper: impl FnMut(char) -> Result<T, E>
+    ) -> AocResult<Grid<T>> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)
+            .map_err(AocError::Stream)?;
+        Ok(Grid::parse(&input, mapper)?)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: i32, height: i32, filler: T) -> Grid<T> {
+        let size: usize = (width * height).try_into().unwrap();
+        Grid {
+            data: vec![filler; size],
+            width,
+            height,
+        }
+    }
+
+    /// Parses grid in the same way as [`Grid::parse`] but pads rows shorter
+    /// than the longest one with the `filler` (e.g. for right-trimmed lines).
+    pub fn parse_padded<E: Display>(
+        input: &str,
+        filler: T,
+        mapper: impl FnMut(char) -> Result<T, E>
+    ) -> Result<Grid<T>, ParseError> {
+        let rows = Grid::parse_rows(input, mapper)?;
+        let width = rows.iter().map(|(_, row)| row.len()).max().unwrap_or(0);
+        let height = rows.len();
+        let mut data = Vec::with_capacity(width * height);
+        for (_, row) in rows {
+            let padding = width - row.len();
+            data.extend(row);
+            data.extend(repeat_n(filler.clone(), padding));
+        }
+        return Ok(Grid::from_cells(data, width as i32, height as i32));
+    }
+
+    pub fn get(&self, at: impl Into<Point2>) -> Option<T> {
+        self.get_ref(at).cloned()
+    }
+
+    /// Builds a grid with the specified size where each cell is cloned from
+    /// the `source` position in this grid.
+    fn remap(&self, width: i32, height: i32, source: impl Fn(i32, i32) -> (i32, i32)) -> Grid<T> {
+        let mut data = Vec::with_capacity((width * height) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                data.push(self[source(x, y)].clone());
+            }
+        }
+        Grid::from_cells(data, width, height)
+    }
+
+    /// Swaps rows and columns (mirrors along the main diagonal).
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Rotates by 90 degrees clockwise.
+    pub fn rotate_90(&self) -> Grid<T> {
+        let height = self.height;
+        self.remap(self.height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        let (width, height) = (self.width, self.height);
+        self.remap(width, height, |x, y| (width - 1 - x, height - 1 - y))
+    }
+
+    /// Rotates by 270 degrees clockwise (90 degrees counter-clockwise).
+    pub fn rotate_270(&self) -> Grid<T> {
+        let width = self.width;
+        self.remap(self.height, self.width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width;
+        self.remap(self.width, self.height, |x, y| (width - 1 - x, y))
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height;
+        self.remap(self.width, self.height, |x, y| (x, height - 1 - y))
+    }
+
+    /// Copies a rectangle with the top-left corner at `at` into a new grid.
+    pub fn crop(&self, at: impl Into<Point2>, width: i32, height: i32) -> Option<Grid<T>> {
+        self.view(at, width, height).map(|view| view.to_grid())
+    }
+
     pub fn fill(&mut self, value: T) {
         for i in 0..self.data.len() {
             self.data[i] = value.clone();
@@ -79,6 +361,22 @@ impl<T: Clone> Grid<T> {
     }
 }
 
+impl<T: PartialEq> Grid<T> {
+    /// Returns the first position with the value in row-major order.
+    pub fn find(&self, value: &T) -> Option<(i32, i32)> {
+        self.positions_where(|v| v == value).next()
+    }
+
+    /// Iterates over all positions with the value in row-major order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (i32, i32)> + 'a {
+        self.positions_where(move |v| v == value)
+    }
+
+    pub fn count(&self, value: &T) -> usize {
+        self.data.iter().filter(|v| *v == value).count()
+    }
+}
+
 impl<T: Clone + Default> Grid<T> {
     pub fn empty(rows: i32, columns: i32) -> Grid<T> {
         Grid::new(rows, columns, T::default())
@@ -95,46 +393,51 @@ impl<T: Clone> Clone for Grid<T> {
     }
 }
 
-impl<T: Clone + PartialEq> Grid<T> {
-    pub fn find(&self, value: &T) -> Option<(i32, i32)> {
-        for i in 0..self.width {
-            for j in 0..self.height {
-                let at = (i, j);
-                if self.get(at).unwrap() == *value {
-                    return Some(at);
-                }
-            }
-        }
-        return None;
+impl<T, P: Into<Point2>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, at: P) -> &T {
+        self.get_ref(at).expect("Grid: trying to get out of bounds")
+    }
+}
+
+impl<T, P: Into<Point2>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, at: P) -> &mut T {
+        self.get_mut(at).expect("Grid: trying to get out of bounds")
     }
 }
 
 impl Grid<char> {
-    pub fn from_lines(lines: &[String]) -> Result<Grid<char>, String> {
-        let height = lines.len();
-        let width = if lines.is_empty() { 0 } else { lines[0].len() };
-        let mut data = vec!['\0'; width * height];
-        let mut offset: usize = 0;
-        for line in lines {
-            if line.len() != width {
-                Err("Grid: inconsistent line length")?;
-            }
-            for ch in line.chars() {
-                data[offset] = ch;
-                offset += 1;
+    /// Creates grid from lines of characters which must have the same length.
+    pub fn from_lines(lines: &[impl AsRef<str>]) -> Result<Grid<char>, String> {
+        let width = lines.first().map_or(0, |line| line.as_ref().chars().count());
+        let mut data = Vec::with_capacity(width * lines.len());
+        for (y, line) in lines.iter().enumerate() {
+            let row_start = data.len();
+            data.extend(line.as_ref().chars());
+            let row_width = data.len() - row_start;
+            if row_width != width {
+                Err(format!("Grid line {} has length {row_width}, expected {width}", y + 1))?;
             }
         }
-        return Ok(Grid {
-            data,
-            width: width.try_into().unwrap(),
-            height: height.try_into().unwrap(),
-        });
+        return Ok(Grid::from_cells(data, width as i32, lines.len() as i32));
+    }
+
+    /// Creates grid from lines of characters padding ones shorter than the longest
+    /// line with the `filler`.
+    pub fn from_lines_padded(lines: &[impl AsRef<str>], filler: char) -> Grid<char> {
+        let width = lines.iter().map(|line| line.as_ref().chars().count()).max().unwrap_or(0);
+        let mut data = Vec::with_capacity(width * lines.len());
+        for line in lines {
+            let row_start = data.len();
+            data.extend(line.as_ref().chars());
+            data.resize(row_start + width, filler);
+        }
+        return Grid::from_cells(data, width as i32, lines.len() as i32);
     }
 
     pub fn lines<'a>(&'a self) -> impl Iterator<Item = String> + 'a {
-        let chunk_size = self.width.try_into().unwrap();
-        self.data.chunks(chunk_size)
-            .map(|line| line.into_iter().collect::<String>() + "\n")
+        self.rows().map(|line| line.iter().collect::<String>() + "\n")
     }
 
     pub fn write_into(&self, writer: &mut LineWriter<impl Write>) -> std::io::Result<()> {
@@ -144,3 +447,132 @@ impl Grid<char> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Direction;
+
+    fn parse_digit(ch: char) -> Result<u32, String> {
+        ch.to_digit(10).ok_or_else(|| format!("expected digit, got '{ch}'"))
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse("123\n456\n", parse_digit).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 1)), Some(6));
+
+        let grid = Grid::read("12\n34\n".as_bytes(), parse_digit).unwrap();
+        assert_eq!(grid.get((0, 1)), Some(3));
+
+        let err = Grid::parse("123\n4x6\n", parse_digit).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.message, "Invalid grid cell: expected digit, got 'x'");
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 2: Invalid grid cell: expected digit, got 'x'\n  |\n2 | 4x6\n  |  ^"
+        );
+
+        let err = Grid::parse("123\n45\n", parse_digit).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "Grid row has length 2, expected 3");
+    }
+
+    #[test]
+    fn test_accessors() {
+        let mut grid = Grid::from([
+            [vec![1], vec![2]],
+            [vec![3], vec![4]],
+        ]);
+        grid[(1, 0)].push(5);
+        grid.get_mut((0, 1)).unwrap().clear();
+        assert_eq!(grid.get_ref((1, 0)), Some(&vec![2, 5]));
+        assert_eq!(grid.get_ref((2, 0)), None);
+
+        for (_, cell) in grid.iter_mut() {
+            cell.push(0);
+        }
+        let cells: Vec<_> = grid.iter().map(|(at, cell)| (at, cell.len())).collect();
+        assert_eq!(cells, vec![((0, 0), 2), ((1, 0), 3), ((0, 1), 1), ((1, 1), 2)]);
+        assert_eq!(grid.positions().collect::<Vec<_>>(), vec![(0, 0), (1, 0), (0, 1), (1, 1)]);
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = Grid::from([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        let rows = |g: &Grid<i32>| g.rows().map(|row| row.to_vec()).collect::<Vec<_>>();
+        assert_eq!(rows(&grid.transpose()), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        assert_eq!(rows(&grid.rotate_90()), vec![vec![4, 1], vec![5, 2], vec![6, 3]]);
+        assert_eq!(rows(&grid.rotate_180()), vec![vec![6, 5, 4], vec![3, 2, 1]]);
+        assert_eq!(rows(&grid.rotate_270()), vec![vec![3, 6], vec![2, 5], vec![1, 4]]);
+        assert_eq!(rows(&grid.flip_horizontal()), vec![vec![3, 2, 1], vec![6, 5, 4]]);
+        assert_eq!(rows(&grid.flip_vertical()), vec![vec![4, 5, 6], vec![1, 2, 3]]);
+        assert_eq!(rows(&grid.rotate_90().rotate_270()), rows(&grid));
+
+        let columns: Vec<Vec<i32>> = grid.columns().map(|c| c.copied().collect()).collect();
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::from([
+            [1, 2, 3],
+            [4, 5, 6],
+        ]);
+        let orthogonal: Vec<_> = grid.neighbors((0, 0), ORTHOGONAL_NEIGHBORS).collect();
+        assert_eq!(orthogonal, vec![((1, 0), &2), ((0, 1), &4)]);
+
+        let wrapped: Vec<_> = grid.neighbors_wrapping((0, 0), ORTHOGONAL_NEIGHBORS)
+            .map(|(at, _)| at)
+            .collect();
+        assert_eq!(wrapped, vec![(0, 1), (1, 0), (0, 1), (2, 0)]);
+
+        assert_eq!(grid.count_neighbors((1, 1), MOORE_NEIGHBORS, |&v| v % 2 == 0), 3);
+        assert_eq!(grid.count_neighbors((1, 0), DIAGONAL_NEIGHBORS, |_| true), 2);
+
+        let at = Point2::new(2, 1);
+        assert_eq!(grid[at], 6);
+        assert_eq!(grid.get(at + Direction::W), Some(5));
+        assert_eq!(grid.count_neighbors(at, &Direction::ALL, |_| true), 3);
+    }
+
+    #[test]
+    fn test_queries() {
+        let grid = Grid::parse(".^S\nS.^\n", Ok::<char, String>).unwrap();
+        assert_eq!(grid.find(&'S'), Some((2, 0)));
+        assert_eq!(grid.find(&'#'), None);
+        assert_eq!(grid.find_all(&'^').collect::<Vec<_>>(), vec![(1, 0), (2, 1)]);
+        assert_eq!(grid.count(&'.'), 2);
+        let markers: Vec<_> = grid.positions_where(|&ch| ch != '.').collect();
+        assert_eq!(markers, vec![(1, 0), (2, 0), (0, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn test_from_lines() {
+        let grid = Grid::from_lines(&["αβγ", "δεζ"]).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'ζ');
+        assert_eq!(
+            Grid::from_lines(&["abc", "αβ"]).unwrap_err(),
+            "Grid line 2 has length 2, expected 3"
+        );
+
+        let padded = Grid::from_lines_padded(&["1 2", "34", "α"], ' ');
+        assert_eq!(padded.lines().collect::<String>(), "1 2\n34 \nα  \n");
+        let parsed = Grid::parse_padded("12\n3\n", 0, parse_digit).unwrap();
+        assert_eq!(parsed.row(1), Some(&[3, 0][..]));
+
+        let blank = Grid::from_lines_padded(&["", ""], '.');
+        assert_eq!((blank.width(), blank.height()), (0, 2));
+        assert_eq!(blank.lines().collect::<String>(), "\n\n");
+        assert_eq!(blank.rows().count(), 2);
+        let empty = Grid::parse_padded("\n", '.', Ok::<char, String>).unwrap();
+        assert_eq!((empty.width(), empty.height()), (0, 0));
+        assert_eq!(empty.lines().count(), 0);
+    }
+}
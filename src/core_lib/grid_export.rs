@@ -0,0 +1,132 @@
+use crate::{AocError, AocResult, Grid, create_output_file};
+use facet::Facet;
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// JSON representation of grid frames where each frame after the first one
+/// is stored as a list of changed cells.
+#[derive(Facet, Clone, Debug, PartialEq)]
+#[facet(rename_all = "camelCase")]
+pub struct GridFrames {
+    pub width: i32,
+    pub height: i32,
+    /// First frame as rows of cell characters.
+    pub initial: Vec<String>,
+    /// For each next frame, cells changed from the previous one as `(x, y, cell)`.
+    pub deltas: Vec<Vec<(i32, i32, char)>>,
+}
+
+/// Records a sequence of same-sized grids for export, e.g. to replay
+/// automaton generations in the browser viewer.
+///
+/// Cells are stored as characters produced by the `cell_char` mapper.
+pub struct GridRecording<T, F> {
+    frames: GridFrames,
+    last: Grid<char>,
+    cell_char: F,
+    cell_type: PhantomData<fn(&T)>,
+}
+
+impl<T, F: Fn(&T) -> char> GridRecording<T, F> {
+    pub fn new(first: &Grid<T>, cell_char: F) -> GridRecording<T, F> {
+        let last = first.map(&cell_char);
+        GridRecording {
+            frames: GridFrames {
+                width: last.width(),
+                height: last.height(),
+                initial: last.rows().map(|row| row.iter().collect()).collect(),
+                deltas: Vec::new(),
+            },
+            last,
+            cell_char,
+            cell_type: PhantomData,
+        }
+    }
+
+    /// Appends a frame which must have the same size as the first one.
+    pub fn push(&mut self, frame: &Grid<T>) -> AocResult<()> {
+        if frame.width() != self.last.width() || frame.height() != self.last.height() {
+            Err(AocError::Invariant(format!(
+                "Recorded frame size {}x{} differs from the first frame {}x{}",
+                frame.width(), frame.height(), self.last.width(), self.last.height()
+            )))?;
+        }
+        let mut delta = Vec::new();
+        for ((at, previous), (_, value)) in self.last.iter_mut().zip(frame.iter()) {
+            let current = (self.cell_char)(value);
+            if *previous != current {
+                delta.push((at.0, at.1, current));
+                *previous = current;
+            }
+        }
+        self.frames.deltas.push(delta);
+        Ok(())
+    }
+
+    /// Count of recorded frames including the first one.
+    pub fn frame_count(&self) -> usize {
+        self.frames.deltas.len() + 1
+    }
+
+    pub fn frames(&self) -> &GridFrames {
+        &self.frames
+    }
+
+    /// Writes frames as JSON creating parent directories if needed.
+    pub fn save(&self, path: impl AsRef<Path>) -> AocResult<()> {
+        let path = path.as_ref();
+        let mut writer = create_output_file(path)?;
+        facet_json::to_writer_std(&mut writer, &self.frames)
+            .map_err(|err| AocError::io(path, err))
+    }
+}
+
+impl GridFrames {
+    /// Restores full frames by applying deltas to the first frame.
+    pub fn to_grids(&self) -> Vec<Grid<char>> {
+        let mut current = Grid::from_lines_padded(&self.initial, ' ');
+        let mut grids = vec![current.clone()];
+        for delta in &self.deltas {
+            for &(x, y, cell) in delta {
+                current.set((x, y), cell);
+            }
+            grids.push(current.clone());
+        }
+        grids
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grid_recording() {
+        let frames = [
+            Grid::from_lines(&["..", "#."]).unwrap(),
+            Grid::from_lines(&["..", "##"]).unwrap(),
+            Grid::from_lines(&["..", "##"]).unwrap(),
+            Grid::from_lines(&["#.", ".#"]).unwrap(),
+        ];
+        let mut recording = GridRecording::new(&frames[0], |&ch| ch);
+        for frame in &frames[1..] {
+            recording.push(frame).unwrap();
+        }
+        assert_eq!(recording.frame_count(), 4);
+        assert_eq!(recording.frames().deltas, vec![
+            vec![(1, 1, '#')],
+            vec![],
+            vec![(0, 0, '#'), (0, 1, '.')],
+        ]);
+
+        let json = facet_json::to_string(recording.frames());
+        let restored: GridFrames = facet_json::from_str(&json).unwrap();
+        assert_eq!(&restored, recording.frames());
+        assert!(restored.to_grids().iter().zip(&frames).all(|(a, b)| a.as_view() == b.as_view()));
+
+        let resized = Grid::from_lines(&["..."]).unwrap();
+        let err = recording.push(&resized).unwrap_err();
+        assert!(matches!(err, AocError::Invariant(_)), "{err}");
+        assert_eq!(recording.frame_count(), 4);
+    }
+}
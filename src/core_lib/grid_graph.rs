@@ -0,0 +1,208 @@
+use crate::{AStar, AStarGraph, AStarNode, Connectivity, Grid, Point2, Vec2};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::ops::Add;
+
+impl AStarNode for Point2 {
+    type Key = Point2;
+
+    fn key(&self) -> Point2 {
+        *self
+    }
+}
+
+/// Graph of grid cells for [`AStar`] where edges lead to passable neighbor cells
+/// and cost of an edge is the cost of entering its target cell, e.g.:
+/// ```
+/// # use core_lib::{Grid, GridGraph};
+/// let grid = Grid::parse("S.#\n.##\n..E\n", Ok::<char, String>).unwrap();
+/// let graph = GridGraph::new(&grid, (0, 0))
+///     .passable(|&ch| ch != '#')
+///     .goal(|_, &ch| ch == 'E');
+/// let (cost, path) = graph.find_path().unwrap();
+/// assert_eq!((cost, path.len()), (4, 5));
+/// ```
+pub struct GridGraph<'a, T, C> {
+    grid: &'a Grid<T>,
+    start: Point2,
+    connectivity: Connectivity,
+    passable: Box<dyn Fn(&T) -> bool + 'a>,
+    cost: Box<dyn Fn(&T) -> C + 'a>,
+    goal: GoalPredicate<'a, T>,
+    estimate: Box<dyn Fn(Point2) -> C + 'a>,
+}
+
+type GoalPredicate<'a, T> = Box<dyn Fn(Point2, &T) -> bool + 'a>;
+
+impl<'a, T> GridGraph<'a, T, u32> {
+    /// Creates a graph with orthogonal moves and unit cost where all cells are passable
+    /// and none is a goal.
+    pub fn new(grid: &'a Grid<T>, start: impl Into<Point2>) -> GridGraph<'a, T, u32> {
+        GridGraph {
+            grid,
+            start: start.into(),
+            connectivity: Connectivity::Four,
+            passable: Box::new(|_| true),
+            cost: Box::new(|_| 1),
+            goal: Box::new(|_, _| false),
+            estimate: Box::new(|_| 0),
+        }
+    }
+}
+
+impl<'a, T, C> GridGraph<'a, T, C>
+where
+    C: Add<Output = C> + Ord + Copy + Default,
+{
+    pub fn passable(self, passable: impl Fn(&T) -> bool + 'a) -> Self {
+        GridGraph { passable: Box::new(passable), ..self }
+    }
+
+    /// Sets cost of entering a cell (resets the estimate to zero as it has the cost type).
+    pub fn cost<D: Default + 'a>(self, cost: impl Fn(&T) -> D + 'a) -> GridGraph<'a, T, D> {
+        GridGraph {
+            grid: self.grid,
+            start: self.start,
+            connectivity: self.connectivity,
+            passable: self.passable,
+            cost: Box::new(cost),
+            goal: self.goal,
+            estimate: Box::new(|_| D::default()),
+        }
+    }
+
+    pub fn goal(self, goal: impl Fn(Point2, &T) -> bool + 'a) -> Self {
+        GridGraph { goal: Box::new(goal), ..self }
+    }
+
+    pub fn connectivity(self, connectivity: Connectivity) -> Self {
+        GridGraph { connectivity, ..self }
+    }
+
+    /// Sets a heuristic for the remaining cost to a goal which must never overestimate it
+    /// (zero by default which makes the search equivalent to Dijkstra's algorithm).
+    pub fn estimate(self, estimate: impl Fn(Point2) -> C + 'a) -> Self {
+        GridGraph { estimate: Box::new(estimate), ..self }
+    }
+
+    /// Finds the cheapest path from the start to a goal cell,
+    /// returns its cost and positions from the start to the goal.
+    pub fn find_path(self) -> Option<(C, Vec<Point2>)> {
+        let mut search = AStar::new(self);
+        while !search.next() {}
+        let (goal, cost) = (*search.found_goal())?;
+        let mut path: Vec<_> = search.iter_back_path(goal).map(|(&at, _)| at).collect();
+        path.reverse();
+        Some((cost, path))
+    }
+
+    /// Computes step counts of shortest paths from `from` to each reachable cell (ignoring costs).
+    pub fn bfs_distances(&self, from: impl Into<Point2>) -> Grid<Option<u32>> {
+        let from = from.into();
+        let mut distances = Grid::new(self.grid.width(), self.grid.height(), None);
+        if !self.is_passable(from) {
+            return distances;
+        }
+        distances.set(from, Some(0));
+        let mut queue = VecDeque::from([(from, 0)]);
+        while let Some((at, steps)) = queue.pop_front() {
+            for (next, _, _) in self.edges(at) {
+                if distances[next].is_none() {
+                    distances.set(next, Some(steps + 1));
+                    queue.push_back((next, steps + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    /// Computes costs of the cheapest paths from `from` to each reachable cell.
+    pub fn dijkstra_distances(&self, from: impl Into<Point2>) -> Grid<Option<C>> {
+        let from = from.into();
+        let mut distances = Grid::new(self.grid.width(), self.grid.height(), None);
+        if !self.is_passable(from) {
+            return distances;
+        }
+        distances.set(from, Some(C::default()));
+        let mut queue = BinaryHeap::from([Reverse((C::default(), from))]);
+        while let Some(Reverse((cost, at))) = queue.pop() {
+            if distances[at].is_some_and(|best| best < cost) {
+                continue;
+            }
+            for (next, _, edge_cost) in self.edges(at) {
+                let next_cost = cost + edge_cost;
+                if distances[next].is_none_or(|best| next_cost < best) {
+                    distances.set(next, Some(next_cost));
+                    queue.push(Reverse((next_cost, next)));
+                }
+            }
+        }
+        distances
+    }
+
+    fn is_passable(&self, at: Point2) -> bool {
+        self.grid.get_ref(at).is_some_and(|value| (self.passable)(value))
+    }
+
+    fn edges(&self, at: Point2) -> impl Iterator<Item = (Point2, Vec2, C)> + '_ {
+        self.grid.neighbors(at, self.connectivity.offsets())
+            .filter(|(_, value)| (self.passable)(value))
+            .map(move |(next, value)| {
+                let next = Point2::from(next);
+                (next, next - at, (self.cost)(value))
+            })
+    }
+}
+
+impl<T, C> AStarGraph<Point2> for GridGraph<'_, T, C>
+where
+    C: Add<Output = C> + Ord + Copy + Default,
+{
+    /// Offset of the move.
+    type Edge = Vec2;
+    type Cost = C;
+
+    fn start(&self) -> Point2 {
+        self.start
+    }
+
+    fn neighbors(&self, node: &Point2) -> impl Iterator<Item = (Point2, Vec2, C)> + '_ {
+        self.edges(*node)
+    }
+
+    fn estimate(&self, node: &Point2) -> C {
+        (self.estimate)(*node)
+    }
+
+    fn is_goal(&self, node: &Point2) -> bool {
+        self.grid.get_ref(*node).is_some_and(|value| (self.goal)(*node, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        let grid = Grid::parse("\
+            .9#\n\
+            .1.\n\
+            ...\n", Ok::<char, String>).unwrap();
+        let graph = GridGraph::new(&grid, (0, 0))
+            .passable(|&ch| ch != '#')
+            .cost(|&ch| ch.to_digit(10).unwrap_or(1));
+
+        let steps = graph.bfs_distances((0, 0));
+        assert_eq!(steps.row(0), Some(&[Some(0), Some(1), None][..]));
+        assert_eq!(steps[(2, 1)], Some(3));
+
+        let costs = graph.dijkstra_distances((0, 0));
+        assert_eq!(costs.row(0), Some(&[Some(0), Some(9), None][..]));
+        assert_eq!(costs[(2, 1)], Some(3));
+
+        let (cost, path) = graph.goal(|at, _| at == Point2::new(2, 1)).find_path().unwrap();
+        assert_eq!(cost, 3);
+        assert_eq!(path, vec![Point2::new(0, 0), Point2::new(0, 1), Point2::new(1, 1), Point2::new(2, 1)]);
+    }
+}
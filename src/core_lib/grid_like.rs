@@ -0,0 +1,150 @@
+use crate::{Bounds, CellStyle, Grid, GridRender, Point2, Vec2};
+
+/// Common operations of dense [`Grid`] and hash-backed [`SparseGrid`](crate::SparseGrid)
+/// for algorithms which work on either representation.
+pub trait GridLike<T> {
+    /// Bounding box of the grid cells or `None` if there are no cells.
+    fn bounds(&self) -> Option<Bounds>;
+
+    /// Returns the cell value or `None` if the position is outside of the grid.
+    fn get_ref(&self, at: impl Into<Point2>) -> Option<&T>;
+
+    fn set(&mut self, at: impl Into<Point2>, value: T);
+
+    fn get(&self, at: impl Into<Point2>) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.get_ref(at).cloned()
+    }
+
+    /// Iterates over positions within the bounds in row-major order.
+    fn positions(&self) -> impl Iterator<Item = (i32, i32)> {
+        let bounds = self.bounds();
+        bounds.into_iter().flat_map(|b| {
+            (b.min.y..=b.max.y).flat_map(move |y| (b.min.x..=b.max.x).map(move |x| (x, y)))
+        })
+    }
+
+    /// Iterates over `(position, value)` of cells at `stencil` offsets from `at`,
+    /// skipping ones outside of the grid.
+    fn neighbors<'a, S: Copy + Into<Vec2>>(
+        &'a self,
+        at: impl Into<Point2>,
+        stencil: &'a [S]
+    ) -> impl Iterator<Item = ((i32, i32), &'a T)> + 'a
+    where
+        T: 'a,
+    {
+        let at: Point2 = at.into();
+        stencil.iter().filter_map(move |&offset| {
+            let next = at + offset.into();
+            self.get_ref(next).map(|value| (next.into(), value))
+        })
+    }
+
+    /// Counts cells at `stencil` offsets from `at` which match the predicate.
+    fn count_neighbors<S: Copy + Into<Vec2>>(
+        &self,
+        at: impl Into<Point2>,
+        stencil: &[S],
+        mut predicate: impl FnMut(&T) -> bool
+    ) -> usize {
+        self.neighbors(at, stencil).filter(|(_, value)| predicate(value)).count()
+    }
+
+    /// Iterates over positions within the bounds with values matching the predicate
+    /// in row-major order (top to bottom, then left to right).
+    fn positions_where<'a>(
+        &'a self,
+        mut predicate: impl FnMut(&T) -> bool + 'a
+    ) -> impl Iterator<Item = (i32, i32)> + 'a
+    where
+        T: 'a,
+    {
+        self.positions().filter(move |&at| self.get_ref(at).is_some_and(&mut predicate))
+    }
+
+    /// Returns the first position with the value in row-major order.
+    fn find(&self, value: &T) -> Option<(i32, i32)>
+    where
+        T: PartialEq,
+    {
+        self.positions_where(|v| v == value).next()
+    }
+
+    /// Iterates over all positions with the value in row-major order.
+    fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (i32, i32)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.positions_where(move |v| v == value)
+    }
+
+    /// Counts cells within the bounds with the value.
+    fn count(&self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        self.find_all(value).count()
+    }
+
+    /// Starts terminal rendering of the cells within the bounds with a style for each cell.
+    fn render<S: Fn(&T) -> CellStyle>(&self, style: S) -> GridRender<'_, T, Self, S>
+    where
+        Self: Sized,
+    {
+        GridRender::new(self, style)
+    }
+}
+
+impl<T> GridLike<T> for Grid<T> {
+    fn bounds(&self) -> Option<Bounds> {
+        if self.width() <= 0 || self.height() <= 0 {
+            return None;
+        }
+        Some(Bounds {
+            min: Point2::new(0, 0),
+            max: Point2::new(self.width() - 1, self.height() - 1),
+        })
+    }
+
+    fn get_ref(&self, at: impl Into<Point2>) -> Option<&T> {
+        Grid::get_ref(self, at)
+    }
+
+    fn set(&mut self, at: impl Into<Point2>, value: T) {
+        Grid::set(self, at, value)
+    }
+
+    fn positions_where<'a>(
+        &'a self,
+        predicate: impl FnMut(&T) -> bool + 'a
+    ) -> impl Iterator<Item = (i32, i32)> + 'a
+    where
+        T: 'a,
+    {
+        Grid::positions_where(self, predicate)
+    }
+
+    fn find(&self, value: &T) -> Option<(i32, i32)>
+    where
+        T: PartialEq,
+    {
+        Grid::find(self, value)
+    }
+
+    fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (i32, i32)> + 'a
+    where
+        T: PartialEq,
+    {
+        Grid::find_all(self, value)
+    }
+
+    fn count(&self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        Grid::count(self, value)
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{Grid, Point2};
+
+/// Zero-copy rectangular part of a [`Grid`] with its own coordinates
+/// (top-left cell of the view is at `(0, 0)`).
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Point2,
+    width: i32,
+    height: i32,
+}
+
+impl<'a, T> GridView<'a, T> {
+    pub(crate) fn new(grid: &'a Grid<T>, origin: Point2, width: i32, height: i32) -> Option<GridView<'a, T>> {
+        let fits = origin.x >= 0 && origin.y >= 0 && width >= 0 && height >= 0 &&
+            origin.x + width <= grid.width() && origin.y + height <= grid.height();
+        fits.then_some(GridView { grid, origin, width, height })
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    /// Position of the view top-left corner in the source grid.
+    pub fn origin(&self) -> Point2 {
+        self.origin
+    }
+
+    pub fn valid(&self, at: impl Into<Point2>) -> bool {
+        let Point2 { x, y } = at.into();
+        x >= 0 && x < self.width &&
+        y >= 0 && y < self.height
+    }
+
+    pub fn get_ref(&self, at: impl Into<Point2>) -> Option<&'a T> {
+        let at = at.into();
+        if self.valid(at) {
+            self.grid.get_ref(Point2::new(self.origin.x + at.x, self.origin.y + at.y))
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: i32) -> Option<&'a [T]> {
+        if y >= 0 && y < self.height {
+            let start = self.origin.x as usize;
+            self.grid.row(self.origin.y + y)
+                .map(|row| &row[start..start + self.width as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + use<'a, T> {
+        let view = *self;
+        (0..self.height).filter_map(move |y| view.row(y))
+    }
+
+    /// Iterates over `(position, value)` of all cells in row-major order
+    /// (positions are relative to the view).
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), &'a T)> + use<'a, T> {
+        self.rows().enumerate().flat_map(|(y, row)| {
+            row.iter().enumerate().map(move |(x, value)| ((x as i32, y as i32), value))
+        })
+    }
+}
+
+impl<T: Clone> GridView<'_, T> {
+    pub fn to_grid(&self) -> Grid<T> {
+        let cells = self.rows().flatten().cloned().collect();
+        Grid::from_cells(cells, self.width, self.height)
+    }
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+/// Views are equal if they have the same size and cells (regardless of origin).
+impl<T: PartialEq> PartialEq for GridView<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width &&
+        self.height == other.height &&
+        self.rows().eq(other.rows())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_views() {
+        let grid = Grid::from([
+            [1, 2, 1, 2],
+            [3, 4, 3, 4],
+            [5, 6, 7, 8],
+        ]);
+        let left = grid.view((0, 0), 2, 2).unwrap();
+        let right = grid.view((2, 0), 2, 2).unwrap();
+        assert!(left == right);
+        assert!(grid.view((1, 0), 2, 2).unwrap() != left);
+        assert!(grid.view((3, 0), 2, 2).is_none());
+
+        let bottom = grid.view((1, 1), 3, 2).unwrap();
+        assert_eq!(bottom.get_ref((2, 1)), Some(&8));
+        assert_eq!(bottom.get_ref((3, 0)), None);
+        assert_eq!(bottom.rows().collect::<Vec<_>>(), vec![&[4, 3, 4][..], &[6, 7, 8][..]]);
+        assert_eq!(bottom.iter().nth(4), Some(((1, 1), &7)));
+
+        let cropped = grid.crop((1, 1), 3, 2).unwrap();
+        assert!(cropped.as_view() == bottom);
+        assert_eq!(grid.crop((0, 0), 0, 0).unwrap().width(), 0);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{AocError, AocResult, Grid, create_output_file};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, LineWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// 24-bit RGB color.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    /// Linearly interpolates between the colors with `t` clamped to `0.0..=1.0`.
+    pub fn lerp(self, other: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(mix(self.0, other.0), mix(self.1, other.1), mix(self.2, other.2))
+    }
+}
+
+/// RGB raster image, e.g. rendered from a grid with [`Grid::to_image`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Image {
+    width: u32,
+    height: u32,
+    /// Row-major RGB bytes.
+    pixels: Vec<u8>,
+}
+
+impl<T> Grid<T> {
+    /// Renders the grid into an image where each cell is a `scale` x `scale` square
+    /// of the color returned by the `palette`.
+    pub fn to_image(&self, scale: u32, mut palette: impl FnMut(&T) -> Rgb) -> Image {
+        let scale = scale.max(1) as usize;
+        let width = self.width() as usize * scale;
+        let height = self.height() as usize * scale;
+        let mut pixels = Vec::with_capacity(width * height * 3);
+        for row in self.rows() {
+            let start = pixels.len();
+            for cell in row {
+                let Rgb(r, g, b) = palette(cell);
+                for _ in 0..scale {
+                    pixels.extend_from_slice(&[r, g, b]);
+                }
+            }
+            // Repeat the scaled row to make square cells
+            for _ in 1..scale {
+                pixels.extend_from_within(start..start + width * 3);
+            }
+        }
+        Image { width: width as u32, height: height as u32, pixels }
+    }
+}
+
+impl Image {
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn get(&self, x: u32, y: u32) -> Option<Rgb> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        let index = (y as usize * self.width as usize + x as usize) * 3;
+        Some(Rgb(self.pixels[index], self.pixels[index + 1], self.pixels[index + 2]))
+    }
+
+    /// Writes the image in binary PPM (P6) format.
+    pub fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(&self.pixels)?;
+        writer.flush()
+    }
+
+    pub fn write_png(&self, writer: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(writer, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.pixels)?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Saves the image in a format chosen by the file extension (`png` or `ppm`),
+    /// creating parent directories if needed.
+    pub fn save(&self, path: impl AsRef<Path>) -> AocResult<()> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|ext| ext.to_str()).unwrap_or("");
+        let result = match extension.to_ascii_lowercase().as_str() {
+            "png" => self.write_png(create_output_file(path)?),
+            "ppm" => self.write_ppm(create_output_file(path)?),
+            _ => Err(io::Error::new(io::ErrorKind::InvalidInput, "unsupported image format")),
+        };
+        result.map_err(|err| AocError::io(path, err))
+    }
+
+    /// Converts pixels into palette indices, fails on more than 256 distinct colors.
+    fn to_indexed(&self) -> io::Result<(Vec<u8>, Vec<u8>)> {
+        let mut colors: HashMap<[u8; 3], u8> = HashMap::new();
+        let mut palette = Vec::new();
+        let mut indices = Vec::with_capacity(self.pixels.len() / 3);
+        for pixel in self.pixels.chunks_exact(3) {
+            let color = [pixel[0], pixel[1], pixel[2]];
+            let index = match colors.get(&color) {
+                Some(&index) => index,
+                None if colors.len() < 256 => {
+                    let index = colors.len() as u8;
+                    colors.insert(color, index);
+                    palette.extend_from_slice(&color);
+                    index
+                }
+                None => Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "GIF frame cannot have more than 256 colors",
+                ))?,
+            };
+            indices.push(index);
+        }
+        Ok((indices, palette))
+    }
+}
+
+/// Animated GIF writer which loops over frames of the same size indefinitely.
+pub struct GifWriter<W: Write> {
+    encoder: gif::Encoder<W>,
+    width: u16,
+    height: u16,
+    /// Frame delay in hundredths of a second.
+    delay: u16,
+}
+
+impl GifWriter<LineWriter<File>> {
+    /// Creates a GIF file at `path` creating parent directories if needed.
+    pub fn create(
+        path: impl AsRef<Path>,
+        width: u32,
+        height: u32,
+        frame_delay: Duration
+    ) -> AocResult<GifWriter<LineWriter<File>>> {
+        let path = path.as_ref();
+        GifWriter::new(create_output_file(path)?, width, height, frame_delay)
+            .map_err(|err| AocError::io(path, err))
+    }
+}
+
+impl<W: Write> GifWriter<W> {
+    pub fn new(writer: W, width: u32, height: u32, frame_delay: Duration) -> io::Result<GifWriter<W>> {
+        let too_large = || io::Error::new(io::ErrorKind::InvalidInput, "GIF frame size is too large");
+        let width = u16::try_from(width).map_err(|_| too_large())?;
+        let height = u16::try_from(height).map_err(|_| too_large())?;
+        let mut encoder = gif::Encoder::new(writer, width, height, &[]).map_err(gif_error)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(gif_error)?;
+        let delay = (frame_delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+        Ok(GifWriter { encoder, width, height, delay })
+    }
+
+    /// Appends a frame which must have the same size as the animation.
+    pub fn write_frame(&mut self, image: &Image) -> io::Result<()> {
+        if image.width != self.width as u32 || image.height != self.height as u32 {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "GIF frame has size {}x{}, expected {}x{}",
+                    image.width, image.height, self.width, self.height
+                ),
+            ))?;
+        }
+        let (indices, palette) = image.to_indexed()?;
+        let mut frame = gif::Frame::from_palette_pixels(self.width, self.height, indices, palette, None);
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame).map_err(gif_error)
+    }
+
+    /// Writes the trailer and returns the underlying writer.
+    pub fn finish(self) -> io::Result<W> {
+        let mut writer = self.encoder.into_inner().map_err(gif_error)?;
+        writer.flush()?;
+        Ok(writer)
+    }
+}
+
+fn gif_error(err: gif::EncodingError) -> io::Error {
+    match err {
+        gif::EncodingError::Io(err) => err,
+        other => io::Error::other(other),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_export() {
+        let grid = Grid::from([[true, false], [false, false]]);
+        let image = grid.to_image(2, |&on| if on { Rgb::WHITE } else { Rgb::BLACK });
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(image.get(1, 1), Some(Rgb::WHITE));
+        assert_eq!(image.get(2, 1), Some(Rgb::BLACK));
+        assert_eq!(image.get(4, 0), None);
+
+        let mut ppm = Vec::new();
+        image.write_ppm(&mut ppm).unwrap();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+
+        let mut png = Vec::new();
+        image.write_png(&mut png).unwrap();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+
+        let mut gif = GifWriter::new(Vec::new(), 4, 4, Duration::from_millis(100)).unwrap();
+        gif.write_frame(&image).unwrap();
+        assert!(gif.write_frame(&Grid::new(1, 1, ()).to_image(1, |_| Rgb::BLACK)).is_err());
+        let gif = gif.finish().unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+        assert_eq!(gif.last(), Some(&0x3b));
+    }
+}
@@ -26,7 +26,7 @@ impl<S: KDSpace> KDTree<S> {
     }
 
     fn construct_node(items: &mut [S::Item], depth: usize) -> Option<KDNode<S::Item>> {
-        if items.len() == 0 {
+        if items.is_empty() {
             return None;
         }
 
@@ -41,9 +41,9 @@ impl<S: KDSpace> KDTree<S> {
         return Some(KDNode {
             item: right[0].clone(),
             left: Self::construct_node(left, depth + 1)
-                .map(|n| Box::new(n)),
+                .map(Box::new),
             right: Self::construct_node(&mut right[1..], depth + 1)
-                .map(|n| Box::new(n)),
+                .map(Box::new),
         });
     }
 
@@ -57,7 +57,7 @@ impl<S: KDSpace> KDTree<S> {
     pub fn find_nearest(&self, to: &S::Item, mut predicate: impl FnMut(&S::Item) -> bool) -> Option<S::Item> {
         match &self.root {
             Some(node) =>
-                Self::find_nearest_with_depth(&node, to, 0, &mut predicate)
+                Self::find_nearest_with_depth(node, to, 0, &mut predicate)
                     .map(|found| found.0),
             _ => None,
         }
@@ -88,19 +88,17 @@ impl<S: KDSpace> KDTree<S> {
         }
 
         // let mut i = 0;
-        for branch in branches {
-            if let Some(node) = branch {
-                // eprintln!("{}branch {i}", prefix);
-                // i += 1;
-                let found = Self::find_nearest_with_depth(node, to, depth + 1, predicate);
-                if let Some((item, distance)) = found &&
-                    best.as_ref().map(|previous| distance < previous.1).unwrap_or(true) &&
-                    predicate(&item)
-                {
-                    best = Some((item, distance));
-                    if distance < plane_distance {
-                        break;
-                    }
+        for node in branches.into_iter().flatten() {
+            // eprintln!("{}branch {i}", prefix);
+            // i += 1;
+            let found = Self::find_nearest_with_depth(node, to, depth + 1, predicate);
+            if let Some((item, distance)) = found &&
+                best.as_ref().map(|previous| distance < previous.1).unwrap_or(true) &&
+                predicate(&item)
+            {
+                best = Some((item, distance));
+                if distance < plane_distance {
+                    break;
                 }
             }
         }
@@ -1,11 +1,45 @@
+mod answers;
+mod automaton;
+mod client;
+mod error;
 mod grid;
+mod grid_export;
+mod grid_graph;
+mod grid_like;
+mod grid_view;
+mod image;
 mod kd_tree;
+mod ngrid;
+mod parse;
 mod path_find;
+mod point;
 mod project;
 mod range_set;
+mod region;
+mod render;
+mod solution;
+mod sparse_grid;
+mod submit;
 
+pub use answers::*;
+pub use automaton::*;
+pub use client::*;
+pub use error::*;
 pub use grid::*;
+pub use grid_export::*;
+pub use grid_graph::*;
+pub use grid_like::*;
+pub use grid_view::*;
+pub use image::*;
 pub use kd_tree::*;
+pub use ngrid::*;
+pub use parse::*;
 pub use path_find::*;
+pub use point::*;
 pub use project::*;
 pub use range_set::*;
+pub use region::*;
+pub use render::*;
+pub use solution::*;
+pub use sparse_grid::*;
+pub use submit::*;
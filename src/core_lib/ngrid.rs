@@ -0,0 +1,271 @@
+use crate::Grid;
+use std::ops::{Index, IndexMut};
+
+/// Dense `D`-dimensional grid where positions are `[i32; D]` coordinates.
+///
+/// Cells are stored with the first axis changing fastest, so for `D = 2`
+/// the order is the same as the row-major order of [`Grid`].
+#[derive(Clone, Debug)]
+pub struct NGrid<T, const D: usize> {
+    data: Vec<T>,
+    size: [i32; D],
+}
+
+/// Returns all `3^D - 1` offsets to cells which differ by at most one in each coordinate.
+pub fn moore_offsets<const D: usize>() -> Vec<[i32; D]> {
+    let count = 3usize.pow(D as u32);
+    (0..count)
+        .map(|mut index| {
+            let mut offset = [0; D];
+            for value in offset.iter_mut() {
+                *value = (index % 3) as i32 - 1;
+                index /= 3;
+            }
+            offset
+        })
+        .filter(|offset| offset.iter().any(|&v| v != 0))
+        .collect()
+}
+
+/// Returns `2 * D` offsets to cells which differ by one in a single coordinate.
+pub fn orthogonal_offsets<const D: usize>() -> Vec<[i32; D]> {
+    (0..D)
+        .flat_map(|axis| [-1, 1].map(|delta| {
+            let mut offset = [0; D];
+            offset[axis] = delta;
+            offset
+        }))
+        .collect()
+}
+
+impl<T, const D: usize> NGrid<T, D> {
+    pub fn size(&self) -> [i32; D] {
+        self.size
+    }
+
+    pub fn map<U>(&self, mapper: impl FnMut(&T) -> U) -> NGrid<U, D> {
+        NGrid {
+            data: self.data.iter().map(mapper).collect(),
+            size: self.size,
+        }
+    }
+
+    pub fn valid(&self, at: [i32; D]) -> bool {
+        self.index_of(at).is_some()
+    }
+
+    fn index_of(&self, at: [i32; D]) -> Option<usize> {
+        let mut index = 0;
+        for axis in (0..D).rev() {
+            if at[axis] < 0 || at[axis] >= self.size[axis] {
+                return None;
+            }
+            index = index * self.size[axis] as usize + at[axis] as usize;
+        }
+        Some(index)
+    }
+
+    fn position_of(&self, mut index: usize) -> [i32; D] {
+        let mut at = [0; D];
+        for (value, &size) in at.iter_mut().zip(&self.size) {
+            *value = (index % size as usize) as i32;
+            index /= size as usize;
+        }
+        at
+    }
+
+    pub fn get_ref(&self, at: [i32; D]) -> Option<&T> {
+        self.index_of(at).map(|index| &self.data[index])
+    }
+
+    pub fn get_mut(&mut self, at: [i32; D]) -> Option<&mut T> {
+        self.index_of(at).map(|index| &mut self.data[index])
+    }
+
+    pub fn set(&mut self, at: [i32; D], value: T) {
+        let index = self.index_of(at).expect("NGrid: position is out of bounds");
+        self.data[index] = value;
+    }
+
+    /// Sets the value if the position is inside the grid, returns whether it was set.
+    pub fn try_set(&mut self, at: [i32; D], value: T) -> bool {
+        match self.index_of(at) {
+            Some(index) => {
+                self.data[index] = value;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Iterates over `(position, value)` with the first axis changing fastest.
+    pub fn iter(&self) -> impl Iterator<Item = ([i32; D], &T)> {
+        self.data.iter().enumerate().map(|(index, value)| (self.position_of(index), value))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = [i32; D]> + '_ {
+        (0..self.data.len()).map(|index| self.position_of(index))
+    }
+
+    /// Iterates over `(position, value)` of cells at `offsets` from `at`,
+    /// skipping ones outside of the grid (e.g. with [`moore_offsets`]).
+    pub fn neighbors<'a>(
+        &'a self,
+        at: [i32; D],
+        offsets: &'a [[i32; D]]
+    ) -> impl Iterator<Item = ([i32; D], &'a T)> + 'a {
+        offsets.iter().filter_map(move |offset| {
+            let next: [i32; D] = std::array::from_fn(|axis| at[axis] + offset[axis]);
+            self.get_ref(next).map(|value| (next, value))
+        })
+    }
+
+    /// Counts cells at `offsets` from `at` which match the predicate.
+    pub fn count_neighbors(
+        &self,
+        at: [i32; D],
+        offsets: &[[i32; D]],
+        mut predicate: impl FnMut(&T) -> bool
+    ) -> usize {
+        self.neighbors(at, offsets).filter(|(_, value)| predicate(value)).count()
+    }
+
+    /// Returns the first position with the value in the storage order (first axis fastest).
+    pub fn find(&self, value: &T) -> Option<[i32; D]>
+    where
+        T: PartialEq,
+    {
+        self.data.iter().position(|v| v == value).map(|index| self.position_of(index))
+    }
+}
+
+impl<T: Clone, const D: usize> NGrid<T, D> {
+    pub fn new(size: [i32; D], filler: T) -> NGrid<T, D> {
+        assert!(size.iter().all(|&s| s >= 0), "NGrid: negative size");
+        let count = size.iter().map(|&s| s as usize).product();
+        NGrid {
+            data: vec![filler; count],
+            size,
+        }
+    }
+
+    pub fn get(&self, at: [i32; D]) -> Option<T> {
+        self.get_ref(at).cloned()
+    }
+
+    pub fn fill(&mut self, value: T) {
+        self.data.fill(value);
+    }
+
+    /// Returns 2D slice through the `at` position along `x_axis` and `y_axis`
+    /// (other coordinates are taken from `at`), e.g. a `z` layer of a 3D grid
+    /// with `slice(0, 1, [0, 0, z])`.
+    ///
+    /// Returns `None` if the axes are invalid or same, or if other coordinates
+    /// of `at` are outside of the grid.
+    pub fn slice(&self, x_axis: usize, y_axis: usize, at: [i32; D]) -> Option<Grid<T>> {
+        if x_axis >= D || y_axis >= D || x_axis == y_axis {
+            return None;
+        }
+        let inside = (0..D)
+            .filter(|&axis| axis != x_axis && axis != y_axis)
+            .all(|axis| at[axis] >= 0 && at[axis] < self.size[axis]);
+        if !inside {
+            return None;
+        }
+        let (width, height) = (self.size[x_axis], self.size[y_axis]);
+        let mut data = Vec::with_capacity((width * height).max(0) as usize);
+        for y in 0..height {
+            for x in 0..width {
+                let mut position = at;
+                position[x_axis] = x;
+                position[y_axis] = y;
+                data.push(self[position].clone());
+            }
+        }
+        Some(Grid::from_cells(data, width, height))
+    }
+}
+
+impl<T> From<Grid<T>> for NGrid<T, 2> {
+    fn from(grid: Grid<T>) -> NGrid<T, 2> {
+        let size = [grid.width(), grid.height()];
+        NGrid { data: grid.into_cells(), size }
+    }
+}
+
+impl<T, const D: usize> Index<[i32; D]> for NGrid<T, D> {
+    type Output = T;
+
+    fn index(&self, at: [i32; D]) -> &T {
+        self.get_ref(at).expect("NGrid: position is out of bounds")
+    }
+}
+
+impl<T, const D: usize> IndexMut<[i32; D]> for NGrid<T, D> {
+    fn index_mut(&mut self, at: [i32; D]) -> &mut T {
+        self.get_mut(at).expect("NGrid: position is out of bounds")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(moore_offsets::<2>().len(), 8);
+        assert_eq!(moore_offsets::<4>().len(), 80);
+        assert_eq!(orthogonal_offsets::<3>(), vec![
+            [-1, 0, 0], [1, 0, 0], [0, -1, 0], [0, 1, 0], [0, 0, -1], [0, 0, 1],
+        ]);
+    }
+
+    #[test]
+    fn test_3d_life() {
+        // Conway Cubes: 3D Game of Life starting from a glider in a single layer
+        const CYCLES: i32 = 6;
+        let start = Grid::parse(".#.\n..#\n###\n", |ch| Ok::<bool, String>(ch == '#')).unwrap();
+        let margin = CYCLES + 1;
+        let mut grid = NGrid::new([3 + 2 * margin, 3 + 2 * margin, 1 + 2 * margin], false);
+        for ((x, y), &active) in start.iter() {
+            grid.set([x + margin, y + margin, margin], active);
+        }
+
+        let offsets = moore_offsets::<3>();
+        for _ in 0..CYCLES {
+            let mut next = grid.clone();
+            for (at, &active) in grid.iter() {
+                let around = grid.count_neighbors(at, &offsets, |&v| v);
+                next.set(at, around == 3 || (active && around == 2));
+            }
+            grid = next;
+        }
+        assert_eq!(grid.iter().filter(|(_, active)| **active).count(), 112);
+
+        let layer = grid.slice(0, 1, [0, 0, margin]).unwrap();
+        assert_eq!((layer.width(), layer.height()), (17, 17));
+        assert_eq!(
+            layer.iter().filter(|(_, active)| **active).count(),
+            grid.positions().filter(|at| at[2] == margin && grid[*at]).count()
+        );
+        assert_eq!(grid.find(&true).map(|at| grid[at]), Some(true));
+
+        assert!(grid.slice(0, 1, [0, 0, -1]).is_none());
+        assert!(grid.slice(0, 1, [0, 0, 1 + 2 * margin]).is_none());
+        assert!(grid.slice(0, 0, [0, 0, margin]).is_none());
+        assert!(grid.slice(0, 3, [0, 0, margin]).is_none());
+        assert!(grid.slice(0, 2, [0, 100, 0]).is_none());
+        assert!(grid.slice(0, 1, [-5, 100, margin]).is_some());
+    }
+
+    #[test]
+    fn test_from_grid() {
+        let grid = NGrid::from(Grid::from([[1, 2, 3], [4, 5, 6]]));
+        assert_eq!(grid.size(), [3, 2]);
+        assert_eq!(grid[[2, 1]], 6);
+        assert_eq!(grid.find(&4), Some([0, 1]));
+        assert_eq!(grid.neighbors([0, 0], &moore_offsets()).count(), 3);
+        assert_eq!(grid.slice(1, 0, [0, 0]).unwrap().row(0), Some(&[1, 4][..]));
+    }
+}
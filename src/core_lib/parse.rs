@@ -0,0 +1,175 @@
+use crate::ParseError;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Part of the parsed input text which remembers the whole source
+/// to report errors with line and column.
+///
+/// All combinators produce sub-slices of the source, e.g.:
+/// ```
+/// # use core_lib::Span;
+/// let ranges = Span::new("1-3,10-12\n").trim().list(",", |r| r.range::<i64>("-"));
+/// assert_eq!(ranges.unwrap(), vec![1..=3, 10..=12]);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Span<'a> {
+    source: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    pub fn new(source: &'a str) -> Span<'a> {
+        Span { source, text: source }
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// Creates a parse error pointing at the start of this span.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError::at(self.source, self.text, message)
+    }
+
+    fn sub(&self, text: &'a str) -> Span<'a> {
+        Span { source: self.source, text }
+    }
+
+    pub fn trim(self) -> Span<'a> {
+        self.sub(self.text.trim())
+    }
+
+    /// Iterates over non-empty lines (without line endings).
+    pub fn lines(self) -> impl Iterator<Item = Span<'a>> {
+        self.text.lines()
+            .filter(|line| !line.is_empty())
+            .map(move |line| self.sub(line))
+    }
+
+    /// Iterates over sections separated by one or more blank lines.
+    pub fn sections(self) -> impl Iterator<Item = Span<'a>> {
+        let text = self.text;
+        let mut sections = Vec::new();
+        let mut start: Option<usize> = None;
+        let mut end = 0;
+        let mut offset = 0;
+        for line in text.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+            if content.trim().is_empty() {
+                if let Some(section_start) = start.take() {
+                    sections.push(self.sub(&text[section_start..end]));
+                }
+            } else {
+                start.get_or_insert(offset);
+                end = offset + content.len();
+            }
+            offset += line.len();
+        }
+        if let Some(section_start) = start {
+            sections.push(self.sub(&text[section_start..end]));
+        }
+        sections.into_iter()
+    }
+
+    /// Splits the span by a delimiter.
+    pub fn split(self, delimiter: &'a str) -> impl Iterator<Item = Span<'a>> {
+        self.text.split(delimiter).map(move |part| self.sub(part))
+    }
+
+    /// Splits the span into exactly two parts at the first delimiter.
+    pub fn split_once(self, delimiter: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        self.text.split_once(delimiter)
+            .map(|(first, second)| (self.sub(first), self.sub(second)))
+            .ok_or_else(|| self.error(format!("Expected '{delimiter}' in: {}", self.text)))
+    }
+
+    /// Parses the whole span as a value, e.g. an integer.
+    pub fn parse<T: FromStr>(self) -> Result<T, ParseError> {
+        self.text.parse().map_err(|_| self.error(format!(
+            "Expected {}, got: {}", std::any::type_name::<T>(), self.text
+        )))
+    }
+
+    /// Parses each non-empty line with `item` parser.
+    pub fn map_lines<T>(
+        self,
+        item: impl FnMut(Span<'a>) -> Result<T, ParseError>
+    ) -> Result<Vec<T>, ParseError> {
+        self.lines().map(item).collect()
+    }
+
+    /// Parses delimited list with `item` parser for each element.
+    pub fn list<T>(
+        self,
+        delimiter: &'a str,
+        item: impl FnMut(Span<'a>) -> Result<T, ParseError>
+    ) -> Result<Vec<T>, ParseError> {
+        self.split(delimiter).map(item).collect()
+    }
+
+    /// Parses exactly `N` delimited values, e.g. `"1,2,3"` as `[1, 2, 3]`.
+    pub fn array<T: FromStr, const N: usize>(self, delimiter: &'a str) -> Result<[T; N], ParseError> {
+        let items = self.list(delimiter, |item| item.parse::<T>())?;
+        let count = items.len();
+        items.try_into().map_err(|_| self.error(format!(
+            "Expected {N} values separated by '{delimiter}', got {count}"
+        )))
+    }
+
+    /// Parses a pair of values separated by the delimiter, e.g. `"a=5"` as `("a", 5)`.
+    pub fn pair<A: FromStr, B: FromStr>(self, delimiter: &str) -> Result<(A, B), ParseError> {
+        let (first, second) = self.split_once(delimiter)?;
+        Ok((first.parse()?, second.parse()?))
+    }
+
+    /// Parses inclusive range such as `"10-14"` with the specified separator.
+    pub fn range<T: FromStr>(self, separator: &str) -> Result<RangeInclusive<T>, ParseError> {
+        let (start, end) = self.split_once(separator)?;
+        Ok(start.parse()?..=end.parse()?)
+    }
+
+    /// Parses a string of decimal digits, e.g. `"0123"` as `[0, 1, 2, 3]`.
+    pub fn digits<T: From<u8>>(self) -> Result<Vec<T>, ParseError> {
+        self.text.char_indices()
+            .map(|(i, ch)| {
+                ch.to_digit(10)
+                    .map(|digit| T::from(digit as u8))
+                    .ok_or_else(|| self.sub(&self.text[i..]).error(format!("Expected digit, got: {ch}")))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        let input = "1-3\n5-7\n\n\n10\n12\n";
+        let sections: Vec<_> = Span::new(input).sections().map(|s| s.text()).collect();
+        assert_eq!(sections, vec!["1-3\n5-7", "10\n12"]);
+
+        let ranges = Span::new(input).sections().next().unwrap()
+            .map_lines(|line| line.range::<i64>("-"))
+            .unwrap();
+        assert_eq!(ranges, vec![1..=3, 5..=7]);
+    }
+
+    #[test]
+    fn test_errors() {
+        let input = "1,2,3\n4,x,6\n";
+        let err = Span::new(input).map_lines(|line| line.array::<i32, 3>(",")).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+
+        let err = Span::new(input).map_lines(|line| line.array::<i32, 2>(",")).unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+
+        let err = Span::new("123\n45a\n").map_lines(|line| line.digits::<u8>()).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
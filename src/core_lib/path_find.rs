@@ -45,7 +45,7 @@ impl<T: Ord + Copy> Ord for PathCost<T> {
 
 impl<T: Ord + Copy> PartialOrd for PathCost<T> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(&other))
+        Some(self.cmp(other))
     }
 }
 
@@ -85,6 +85,7 @@ impl<N: AStarNode, G: AStarGraph<N>> AStar<N, G> {
         &self.found_goal
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> bool {
         if self.found_goal.is_some() {
             return true;
@@ -0,0 +1,269 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// Position on a 2D grid (`y` grows downwards).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Offset between two [`Point2`] positions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2 {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Point2 {
+    pub const fn new(x: i32, y: i32) -> Point2 {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan_distance(self, other: Point2) -> i32 {
+        (other - self).manhattan_length()
+    }
+
+    /// Distance where diagonal step counts as one (as for a chess king).
+    pub fn chebyshev_distance(self, other: Point2) -> i32 {
+        (other - self).chebyshev_length()
+    }
+}
+
+impl Vec2 {
+    pub const fn new(x: i32, y: i32) -> Vec2 {
+        Vec2 { x, y }
+    }
+
+    pub fn manhattan_length(self) -> i32 {
+        self.x.abs() + self.y.abs()
+    }
+
+    pub fn chebyshev_length(self) -> i32 {
+        self.x.abs().max(self.y.abs())
+    }
+}
+
+impl From<(i32, i32)> for Point2 {
+    fn from((x, y): (i32, i32)) -> Point2 {
+        Point2 { x, y }
+    }
+}
+
+impl From<Point2> for (i32, i32) {
+    fn from(p: Point2) -> (i32, i32) {
+        (p.x, p.y)
+    }
+}
+
+impl From<(i32, i32)> for Vec2 {
+    fn from((x, y): (i32, i32)) -> Vec2 {
+        Vec2 { x, y }
+    }
+}
+
+impl From<Vec2> for (i32, i32) {
+    fn from(v: Vec2) -> (i32, i32) {
+        (v.x, v.y)
+    }
+}
+
+impl Add<Vec2> for Point2 {
+    type Output = Point2;
+    fn add(self, v: Vec2) -> Point2 {
+        Point2 { x: self.x + v.x, y: self.y + v.y }
+    }
+}
+
+impl Sub<Vec2> for Point2 {
+    type Output = Point2;
+    fn sub(self, v: Vec2) -> Point2 {
+        Point2 { x: self.x - v.x, y: self.y - v.y }
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Vec2;
+    fn sub(self, other: Point2) -> Vec2 {
+        Vec2 { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl AddAssign<Vec2> for Point2 {
+    fn add_assign(&mut self, v: Vec2) {
+        *self = *self + v;
+    }
+}
+
+impl SubAssign<Vec2> for Point2 {
+    fn sub_assign(&mut self, v: Vec2) {
+        *self = *self - v;
+    }
+}
+
+impl From<Direction> for Vec2 {
+    fn from(direction: Direction) -> Vec2 {
+        direction.offset()
+    }
+}
+
+impl Add<Direction> for Point2 {
+    type Output = Point2;
+    fn add(self, direction: Direction) -> Point2 {
+        self + direction.offset()
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2 { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2 { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl Mul<i32> for Vec2 {
+    type Output = Vec2;
+    fn mul(self, k: i32) -> Vec2 {
+        Vec2 { x: self.x * k, y: self.y * k }
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+    fn neg(self) -> Vec2 {
+        Vec2 { x: -self.x, y: -self.y }
+    }
+}
+
+/// Inclusive rectangle bounds on a 2D grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl Bounds {
+    /// Creates bounds containing a single point.
+    pub fn at(p: Point2) -> Bounds {
+        Bounds { min: p, max: p }
+    }
+
+    pub fn width(&self) -> i32 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i32 {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn contains(&self, p: Point2) -> bool {
+        p.x >= self.min.x && p.x <= self.max.x &&
+        p.y >= self.min.y && p.y <= self.max.y
+    }
+
+    /// Extends the bounds to include the point.
+    pub fn include(&mut self, p: Point2) {
+        self.min = Point2::new(self.min.x.min(p.x), self.min.y.min(p.y));
+        self.max = Point2::new(self.max.x.max(p.x), self.max.y.max(p.y));
+    }
+}
+
+/// Compass direction on a grid where north is up (towards smaller `y`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction {
+    /// Orthogonal directions clockwise starting from north.
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::N, Direction::E, Direction::S, Direction::W];
+
+    /// Diagonal directions clockwise starting from north-east.
+    pub const DIAGONAL: [Direction; 4] = [Direction::NE, Direction::SE, Direction::SW, Direction::NW];
+
+    /// All directions clockwise starting from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::N, Direction::NE, Direction::E, Direction::SE,
+        Direction::S, Direction::SW, Direction::W, Direction::NW,
+    ];
+
+    pub fn offset(self) -> Vec2 {
+        match self {
+            Direction::N => Vec2::new(0, -1),
+            Direction::NE => Vec2::new(1, -1),
+            Direction::E => Vec2::new(1, 0),
+            Direction::SE => Vec2::new(1, 1),
+            Direction::S => Vec2::new(0, 1),
+            Direction::SW => Vec2::new(-1, 1),
+            Direction::W => Vec2::new(-1, 0),
+            Direction::NW => Vec2::new(-1, -1),
+        }
+    }
+
+    /// Rotates by 90 degrees counter-clockwise.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(-2)
+    }
+
+    /// Rotates by 90 degrees clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    /// Rotates clockwise by `steps` of 45 degrees (counter-clockwise if negative).
+    pub fn rotate(self, steps: i32) -> Direction {
+        let index = Direction::ALL.iter().position(|&d| d == self).unwrap() as i32;
+        Direction::ALL[(index + steps).rem_euclid(8) as usize]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        let offset = self.offset();
+        offset.x != 0 && offset.y != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_ops() {
+        let a = Point2::new(1, 2);
+        let b = Point2::from((4, -2));
+        assert_eq!(b - a, Vec2::new(3, -4));
+        assert_eq!(a + Vec2::new(3, -4) * 2, Point2::new(7, -6));
+        assert_eq!(a + Direction::N, Point2::new(1, 1));
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.chebyshev_distance(b), 4);
+        assert_eq!(<(i32, i32)>::from(a), (1, 2));
+    }
+
+    #[test]
+    fn test_direction_turns() {
+        assert_eq!(Direction::N.turn_right(), Direction::E);
+        assert_eq!(Direction::N.turn_left(), Direction::W);
+        assert_eq!(Direction::NE.turn_left(), Direction::NW);
+        assert_eq!(Direction::SW.opposite(), Direction::NE);
+        assert_eq!(Direction::W.rotate(1), Direction::NW);
+        for direction in Direction::ALL {
+            assert_eq!(direction.opposite().offset(), -direction.offset());
+        }
+    }
+}
@@ -1,8 +1,203 @@
+use crate::{AocError, AocResult, ParseError};
+use facet::Facet;
+use std::fs::{File, create_dir_all, read_to_string};
+use std::io::{self, LineWriter};
 use std::path::{Path, PathBuf};
-use std::time::Instant;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
 
+pub const DEFAULT_YEAR: u32 = 2025;
+
+/// Environment variable with the data root directory.
+pub const DATA_DIR_ENV: &str = "AOC_DATA_DIR";
+
+/// Config file name searched for in the current directory and its ancestors.
+pub const CONFIG_FILE: &str = "aoc.json";
+
+static DATA_PATHS: OnceLock<DataPaths> = OnceLock::new();
+
+/// Returns path inside the data directory for the configured year,
+/// e.g. `get_data_path("output/puzzle04.txt")`.
+///
+/// Uses paths set with [`configure_data_paths`] or resolves default ones
+/// for [`DEFAULT_YEAR`] on the first call.
 pub fn get_data_path(data_path: impl AsRef<Path>) -> PathBuf {
-    return Path::new("../advent-of-code-data/2025").join(data_path);
+    data_paths().get(data_path)
+}
+
+/// Input file path relative to the year data directory.
+pub fn input_file(day: u32) -> PathBuf {
+    PathBuf::from(format!("input/puzzle{day:02}.txt"))
+}
+
+pub fn read_input_file(path: impl AsRef<Path>) -> AocResult<String> {
+    let path = path.as_ref();
+    read_to_string(path).map_err(|err| AocError::io(path, err))
+}
+
+/// Reads a JSON file, reporting a malformed one as a parse error at the failed location.
+pub(crate) fn read_json_file<T: Facet<'static>>(path: &Path) -> AocResult<T> {
+    let content = read_input_file(path)?;
+    facet_json::from_str(&content).map_err(|err| {
+        let offset = err.span.map_or(0, |span| span.offset);
+        ParseError::at_offset(&content, offset, err.to_string()).with_file(path).into()
+    })
+}
+
+/// Creates (or truncates) a file at `path` for line-oriented output,
+/// creating its parent directories if needed.
+pub fn create_output_file(path: impl AsRef<Path>) -> AocResult<LineWriter<File>> {
+    let path = path.as_ref();
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).map_err(|err| AocError::io(parent, err))?;
+    }
+    let file = File::create(path).map_err(|err| AocError::io(path, err))?;
+    Ok(LineWriter::new(file))
+}
+
+/// Sets data paths to use for [`get_data_path`].
+///
+/// Returns an error if the paths were already configured or used.
+pub fn configure_data_paths(paths: DataPaths) -> AocResult<()> {
+    DATA_PATHS.set(paths)
+        .map_err(|_| AocError::Invariant("Data paths are already configured".into()))
+}
+
+pub fn data_paths() -> &'static DataPaths {
+    DATA_PATHS.get_or_init(|| {
+        DataPaths::resolve(DEFAULT_YEAR, None).unwrap_or_else(|err| {
+            eprintln!("Failed to resolve data paths, using defaults: {err}");
+            DataPaths::from_roots(DEFAULT_YEAR, vec![DataRoot::default_root()])
+        })
+    })
+}
+
+/// Candidate data root directories for a specific year, in order of precedence.
+#[derive(Clone)]
+pub struct DataPaths {
+    year: u32,
+    roots: Vec<DataRoot>,
+}
+
+#[derive(Clone)]
+pub struct DataRoot {
+    pub path: PathBuf,
+    pub source: String,
+}
+
+/// Local settings from `aoc.json` (not checked in since it holds the session token).
+#[derive(Facet, Default)]
+#[facet(rename_all = "camelCase")]
+pub struct AocConfig {
+    pub data_dir: Option<String>,
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl AocConfig {
+    /// Loads the config file from the current directory or its ancestors
+    /// and returns it together with the file path.
+    pub fn load() -> AocResult<Option<(PathBuf, AocConfig)>> {
+        let Some(config_path) = find_config_file() else {
+            return Ok(None);
+        };
+        let config = read_json_file(&config_path)?;
+        Ok(Some((config_path, config)))
+    }
+}
+
+impl DataRoot {
+    fn default_root() -> DataRoot {
+        DataRoot {
+            path: PathBuf::from("../advent-of-code-data"),
+            source: "default".into(),
+        }
+    }
+}
+
+impl DataPaths {
+    /// Collects data root candidates from (in order of precedence):
+    ///   - explicit directory (e.g. `--data-dir` command-line flag);
+    ///   - `AOC_DATA_DIR` environment variable;
+    ///   - `dataDir` in `aoc.json` config file (relative to the file itself);
+    ///   - `../advent-of-code-data` relative to the current directory.
+    pub fn resolve(year: u32, data_dir: Option<PathBuf>) -> AocResult<DataPaths> {
+        let mut roots = Vec::new();
+        if let Some(path) = data_dir {
+            roots.push(DataRoot { path, source: "--data-dir".into() });
+        }
+        if let Some(path) = std::env::var_os(DATA_DIR_ENV) && !path.is_empty() {
+            roots.push(DataRoot { path: path.into(), source: DATA_DIR_ENV.into() });
+        }
+        if let Some((config_path, config)) = AocConfig::load()?
+            && let Some(data_dir) = config.data_dir
+        {
+            let base = config_path.parent().unwrap_or(Path::new("."));
+            roots.push(DataRoot {
+                path: base.join(data_dir),
+                source: config_path.display().to_string(),
+            });
+        }
+        roots.push(DataRoot::default_root());
+        Ok(DataPaths::from_roots(year, roots))
+    }
+
+    pub fn from_roots(year: u32, roots: Vec<DataRoot>) -> DataPaths {
+        DataPaths { year, roots }
+    }
+
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn roots(&self) -> &[DataRoot] {
+        &self.roots
+    }
+
+    /// Returns the data directory for the year: the first candidate
+    /// where it exists or the most preferred one otherwise.
+    pub fn year_dir(&self) -> PathBuf {
+        let mut dirs = self.roots.iter().map(|root| self.year_dir_in(root));
+        let first = dirs.next().unwrap_or_else(|| PathBuf::from(self.year.to_string()));
+        if first.is_dir() {
+            return first;
+        }
+        dirs.find(|dir| dir.is_dir()).unwrap_or(first)
+    }
+
+    pub fn get(&self, data_path: impl AsRef<Path>) -> PathBuf {
+        self.year_dir().join(data_path)
+    }
+
+    /// Finds an existing file in any of the data root candidates.
+    ///
+    /// Returns a "not found" I/O error with every checked location if the file is missing.
+    pub fn find(&self, data_path: impl AsRef<Path>) -> AocResult<PathBuf> {
+        let data_path = data_path.as_ref();
+        let mut checked = Vec::new();
+        for root in &self.roots {
+            let path = self.year_dir_in(root).join(data_path);
+            if path.is_file() {
+                return Ok(path);
+            }
+            checked.push(format!("  - {} (from {})", path.display(), root.source));
+        }
+        Err(AocError::io(data_path, io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("data file not found, checked locations:\n{}", checked.join("\n")),
+        )))
+    }
+
+    fn year_dir_in(&self, root: &DataRoot) -> PathBuf {
+        root.path.join(self.year.to_string())
+    }
+}
+
+fn find_config_file() -> Option<PathBuf> {
+    let current = std::env::current_dir().ok()?;
+    current.ancestors()
+        .map(|dir| dir.join(CONFIG_FILE))
+        .find(|path| path.is_file())
 }
 
 pub struct MeasureElapsed {
@@ -18,4 +213,119 @@ impl MeasureElapsed {
         println!("{name} elapsed time: {:.2?}", self.start.elapsed());
         self.start = Instant::now();
     }
+
+    /// Runs `action` for `warmup` times without measuring
+    /// then collects elapsed time statistics over `samples` runs.
+    pub fn benchmark<R, E>(
+        options: &BenchOptions,
+        mut action: impl FnMut() -> Result<R, E>
+    ) -> Result<ElapsedStats, E> {
+        for _ in 0..options.warmup {
+            std::hint::black_box(action()?);
+        }
+        let mut samples = Vec::with_capacity(options.samples);
+        for _ in 0..options.samples {
+            let start = Instant::now();
+            std::hint::black_box(action()?);
+            samples.push(start.elapsed());
+        }
+        Ok(ElapsedStats::from_samples(&samples))
+    }
+}
+
+pub struct BenchOptions {
+    pub warmup: usize,
+    pub samples: usize,
+}
+
+#[derive(Facet, Clone)]
+#[facet(rename_all = "camelCase")]
+pub struct ElapsedStats {
+    pub samples: usize,
+    pub min_ns: f64,
+    pub median_ns: f64,
+    pub mean_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl ElapsedStats {
+    pub fn from_samples(samples: &[Duration]) -> ElapsedStats {
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(|a, b| a.total_cmp(b));
+
+        let count = nanos.len();
+        if count == 0 {
+            return ElapsedStats { samples: 0, min_ns: 0.0, median_ns: 0.0, mean_ns: 0.0, stddev_ns: 0.0 };
+        }
+
+        let median_ns = if count.is_multiple_of(2) {
+            (nanos[count / 2 - 1] + nanos[count / 2]) / 2.0
+        } else {
+            nanos[count / 2]
+        };
+        let mean_ns = nanos.iter().sum::<f64>() / count as f64;
+        let variance = if count > 1 {
+            nanos.iter().map(|n| (n - mean_ns).powi(2)).sum::<f64>() / (count - 1) as f64
+        } else {
+            0.0
+        };
+
+        ElapsedStats {
+            samples: count,
+            min_ns: nanos[0],
+            median_ns,
+            mean_ns,
+            stddev_ns: variance.sqrt(),
+        }
+    }
+
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns as u64)
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns as u64)
+    }
+
+    pub fn mean(&self) -> Duration {
+        Duration::from_nanos(self.mean_ns as u64)
+    }
+
+    pub fn stddev(&self) -> Duration {
+        Duration::from_nanos(self.stddev_ns as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_errors() {
+        let root = std::env::temp_dir().join(format!("aoc-project-{}", std::process::id()));
+        let json_path = root.join("config.json");
+        create_dir_all(&root).unwrap();
+        std::fs::write(&json_path, "{\n  \"dataDir\": 42\n}\n").unwrap();
+
+        let parsed = read_json_file::<AocConfig>(&json_path);
+        let root_candidate = DataRoot { path: root.clone(), source: "test".into() };
+        let paths = DataPaths::from_roots(2025, vec![root_candidate]);
+        let found = paths.find(input_file(1));
+        std::fs::remove_dir_all(&root).unwrap();
+
+        match parsed {
+            Err(AocError::Parse(err)) => {
+                assert_eq!(err.file.as_deref(), Some(json_path.as_path()));
+                assert_eq!(err.line, 2);
+            }
+            _ => panic!("expected parse error"),
+        }
+        match found {
+            Err(AocError::Io { path, source }) => {
+                assert_eq!(path, input_file(1));
+                assert_eq!(source.kind(), io::ErrorKind::NotFound);
+            }
+            _ => panic!("expected not found error"),
+        }
+    }
 }
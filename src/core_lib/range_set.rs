@@ -35,11 +35,11 @@ impl<T: Copy + Ord> RangeSet<T> {
             .map(|index| self.get_current_range(index, p))?
     }
 
-    /// Finds the lowest range index `i` such that `ranges[i].source <= point`.
+    /// Finds the highest range index `i` such that `ranges[i].start() <= p`.
     fn find_range_index(&self, p: T) -> Option<usize> {
         self.ranges
-            .binary_search_by(|probe| probe.start().cmp(&p))
-            .ok()
+            .partition_point(|probe| *probe.start() <= p)
+            .checked_sub(1)
     }
 
     fn get_current_range(&self, range_index: usize, p: T) -> Option<RangeInclusive<T>> {
@@ -55,3 +55,27 @@ impl<T: Copy + Ord> RangeSet<T> {
         &self.ranges
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_range() {
+        let set = RangeSet::new(&[10..=14, 3..=5, 12..=18, 16..=20]);
+        assert_eq!(set.ranges(), &vec![3..=5, 10..=20]);
+
+        assert_eq!(set.find_range(4), Some(3..=5));
+        assert_eq!(set.find_range(15), Some(10..=20));
+
+        assert_eq!(set.find_range(3), Some(3..=5));
+        assert_eq!(set.find_range(10), Some(10..=20));
+
+        assert_eq!(set.find_range(5), Some(3..=5));
+        assert_eq!(set.find_range(20), Some(10..=20));
+
+        assert_eq!(set.find_range(1), None);
+        assert_eq!(set.find_range(7), None);
+        assert_eq!(set.find_range(21), None);
+    }
+}
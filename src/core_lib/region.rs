@@ -0,0 +1,180 @@
+use crate::{Bounds, Grid, MOORE_NEIGHBORS, ORTHOGONAL_NEIGHBORS, Point2};
+use std::collections::VecDeque;
+
+/// Which cells are considered adjacent when growing a region.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Orthogonal neighbors only.
+    Four,
+    /// Orthogonal and diagonal neighbors.
+    Eight,
+}
+
+impl Connectivity {
+    pub fn offsets(self) -> &'static [(i32, i32)] {
+        match self {
+            Connectivity::Four => ORTHOGONAL_NEIGHBORS,
+            Connectivity::Eight => MOORE_NEIGHBORS,
+        }
+    }
+}
+
+/// Result of connected-component labeling: each cell has a label from `0` to `count - 1`.
+pub struct Regions {
+    pub labels: Grid<usize>,
+    pub count: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegionStats {
+    pub label: usize,
+    pub area: usize,
+    /// Count of cell edges bordering other regions or the grid boundary.
+    pub perimeter: usize,
+    pub bounds: Bounds,
+    /// Count of straight border segments (equal to the corner count).
+    pub sides: usize,
+}
+
+impl<T> Grid<T> {
+    /// Returns positions reachable from `seed` through cells matching the predicate
+    /// in breadth-first order (empty if `seed` itself does not match).
+    pub fn flood_fill(
+        &self,
+        seed: impl Into<Point2>,
+        connectivity: Connectivity,
+        mut predicate: impl FnMut(&T) -> bool
+    ) -> Vec<(i32, i32)> {
+        let seed: (i32, i32) = seed.into().into();
+        let mut visited = Grid::new(self.width(), self.height(), false);
+        let mut filled = Vec::new();
+        match self.get_ref(seed) {
+            Some(value) if predicate(value) => {}
+            _ => return filled,
+        }
+
+        let mut queue = VecDeque::from([seed]);
+        visited.set(seed, true);
+        while let Some(at) = queue.pop_front() {
+            filled.push(at);
+            for (next, value) in self.neighbors(at, connectivity.offsets()) {
+                if !visited[next] && predicate(value) {
+                    visited.set(next, true);
+                    queue.push_back(next);
+                }
+            }
+        }
+        return filled;
+    }
+
+    /// Labels connected regions where neighbor cells belong to the same region
+    /// if `connected(a, b)` holds for them. Labels are assigned in row-major order
+    /// of the first cell of each region.
+    pub fn label_regions(
+        &self,
+        connectivity: Connectivity,
+        mut connected: impl FnMut(&T, &T) -> bool
+    ) -> Regions {
+        const UNLABELED: usize = usize::MAX;
+        let mut labels = Grid::new(self.width(), self.height(), UNLABELED);
+        let mut count = 0;
+        let mut queue = VecDeque::new();
+        for start in self.positions() {
+            if labels[start] != UNLABELED {
+                continue;
+            }
+            labels.set(start, count);
+            queue.push_back(start);
+            while let Some(at) = queue.pop_front() {
+                let value = &self[at];
+                for (next, next_value) in self.neighbors(at, connectivity.offsets()) {
+                    if labels[next] == UNLABELED && connected(value, next_value) {
+                        labels.set(next, count);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            count += 1;
+        }
+        Regions { labels, count }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Labels connected regions of equal cells.
+    pub fn label_equal_regions(&self, connectivity: Connectivity) -> Regions {
+        self.label_regions(connectivity, |a, b| a == b)
+    }
+}
+
+impl Regions {
+    /// Computes statistics for each region ordered by label.
+    pub fn stats(&self) -> Vec<RegionStats> {
+        let labels = &self.labels;
+        let mut stats: Vec<Option<RegionStats>> = vec![None; self.count];
+        for (at, &label) in labels.iter() {
+            let same = |dx: i32, dy: i32| labels.get_ref((at.0 + dx, at.1 + dy)) == Some(&label);
+
+            let perimeter = ORTHOGONAL_NEIGHBORS.iter()
+                .filter(|&&(dx, dy)| !same(dx, dy))
+                .count();
+            // Each side has two ends, and each corner is shared by two sides
+            let corners = [(1, 1), (1, -1), (-1, 1), (-1, -1)].iter()
+                .filter(|&&(dx, dy)| {
+                    let (horizontal, vertical) = (same(dx, 0), same(0, dy));
+                    let convex = !horizontal && !vertical;
+                    let concave = horizontal && vertical && !same(dx, dy);
+                    convex || concave
+                })
+                .count();
+
+            let entry = stats[label].get_or_insert_with(|| RegionStats {
+                label,
+                area: 0,
+                perimeter: 0,
+                bounds: Bounds::at(at.into()),
+                sides: 0,
+            });
+            entry.area += 1;
+            entry.perimeter += perimeter;
+            entry.sides += corners;
+            entry.bounds.include(at.into());
+        }
+        stats.into_iter().flatten().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = Grid::parse("#..\n.#.\n..#\n", Ok::<char, String>).unwrap();
+        assert_eq!(grid.flood_fill((0, 0), Connectivity::Four, |&c| c == '#'), vec![(0, 0)]);
+        assert_eq!(grid.flood_fill((0, 0), Connectivity::Eight, |&c| c == '#').len(), 3);
+        assert_eq!(grid.flood_fill((1, 0), Connectivity::Four, |&c| c == '.'), vec![(1, 0), (2, 0), (2, 1)]);
+        assert!(grid.flood_fill((1, 0), Connectivity::Four, |&c| c == '#').is_empty());
+    }
+
+    #[test]
+    fn test_region_stats() {
+        let grid = Grid::parse("AAAA\nBBCD\nBBCC\nEEEC\n", Ok::<char, String>).unwrap();
+        let regions = grid.label_equal_regions(Connectivity::Four);
+        assert_eq!(regions.count, 5);
+        assert_eq!(regions.labels.row(1), Some(&[1, 1, 2, 3][..]));
+
+        let summary: Vec<_> = regions.stats().iter()
+            .map(|s| (grid[s.bounds.min], s.area, s.perimeter, s.sides))
+            .collect();
+        assert_eq!(summary, vec![
+            ('A', 4, 10, 4),
+            ('B', 4, 8, 4),
+            ('C', 4, 10, 8),
+            ('D', 1, 4, 4),
+            ('E', 3, 8, 4),
+        ]);
+        let c = &regions.stats()[2];
+        assert_eq!((c.bounds.min, c.bounds.max), (Point2::new(2, 1), Point2::new(3, 3)));
+    }
+}
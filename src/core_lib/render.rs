@@ -0,0 +1,205 @@
+use crate::{GridLike, Point2, Rgb};
+use std::collections::HashMap;
+use std::io::{self, IsTerminal, Write};
+use std::marker::PhantomData;
+
+/// Character and colors used to print a grid cell to the terminal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CellStyle {
+    pub ch: char,
+    pub fg: Option<Rgb>,
+    pub bg: Option<Rgb>,
+}
+
+impl CellStyle {
+    pub fn plain(ch: char) -> CellStyle {
+        CellStyle { ch, fg: None, bg: None }
+    }
+
+    pub fn fg(self, color: Rgb) -> CellStyle {
+        CellStyle { fg: Some(color), ..self }
+    }
+
+    pub fn bg(self, color: Rgb) -> CellStyle {
+        CellStyle { bg: Some(color), ..self }
+    }
+}
+
+impl From<char> for CellStyle {
+    fn from(ch: char) -> CellStyle {
+        CellStyle::plain(ch)
+    }
+}
+
+/// Terminal rendering of a grid created with [`GridLike::render`], e.g. to show a path:
+/// ```
+/// # use core_lib::{CellStyle, Grid, GridLike, Rgb};
+/// let grid = Grid::from([['.', '#'], ['.', '.']]);
+/// let mut out = Vec::new();
+/// grid.render(|&ch| CellStyle::plain(ch))
+///     .highlight([(0, 0), (0, 1), (1, 1)], Rgb(200, 40, 40))
+///     .color(false)
+///     .write(&mut out)
+///     .unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), ".#\n..\n");
+/// ```
+pub struct GridRender<'a, T, G, S> {
+    grid: &'a G,
+    style: S,
+    highlights: HashMap<Point2, Rgb>,
+    rulers: bool,
+    color: Option<bool>,
+    cell: PhantomData<fn(&T)>,
+}
+
+impl<'a, T, G: GridLike<T>, S: Fn(&T) -> CellStyle> GridRender<'a, T, G, S> {
+    pub(crate) fn new(grid: &'a G, style: S) -> GridRender<'a, T, G, S> {
+        GridRender {
+            grid,
+            style,
+            highlights: HashMap::new(),
+            rulers: false,
+            color: None,
+            cell: PhantomData,
+        }
+    }
+
+    /// Sets background color for the positions (later highlights take precedence).
+    pub fn highlight<P: Into<Point2>>(mut self, positions: impl IntoIterator<Item = P>, bg: Rgb) -> Self {
+        for at in positions {
+            self.highlights.insert(at.into(), bg);
+        }
+        self
+    }
+
+    /// Enables row numbers on the left and column numbers above the grid.
+    pub fn rulers(mut self, enabled: bool) -> Self {
+        self.rulers = enabled;
+        self
+    }
+
+    /// Forces colored or plain output (by default only [`print`](Self::print) to a TTY is colored).
+    pub fn color(mut self, enabled: bool) -> Self {
+        self.color = Some(enabled);
+        self
+    }
+
+    /// Prints to stdout, with colors only if it is a terminal unless forced with [`color`](Self::color).
+    pub fn print(&self) -> io::Result<()> {
+        let stdout = io::stdout();
+        let color = self.color.unwrap_or_else(|| stdout.is_terminal());
+        let mut writer = stdout.lock();
+        self.write_with(&mut writer, color)
+    }
+
+    /// Writes to the writer, plain text unless colors are forced with [`color`](Self::color).
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        self.write_with(writer, self.color.unwrap_or(false))
+    }
+
+    fn write_with(&self, writer: &mut impl Write, color: bool) -> io::Result<()> {
+        let Some(bounds) = self.grid.bounds() else {
+            return writer.flush();
+        };
+        let (min, max) = (bounds.min, bounds.max);
+        let label_width = min.y.to_string().len().max(max.y.to_string().len());
+        let dim = |text: String| if color { format!("\x1b[2m{text}\x1b[0m") } else { text };
+
+        if self.rulers {
+            let digits = min.x.unsigned_abs().max(max.x.unsigned_abs()).to_string().len() as u32;
+            if min.x < 0 {
+                let signs: String = (min.x..=max.x).map(|x| if x < 0 { '-' } else { ' ' }).collect();
+                writeln!(writer, "{:label_width$} {}", "", dim(signs))?;
+            }
+            // Column numbers are written vertically, most significant digit first
+            for power in (0..digits).rev() {
+                let divisor = 10u32.pow(power);
+                let line: String = (min.x..=max.x)
+                    .map(|x| {
+                        let x = x.unsigned_abs();
+                        let show = x >= divisor || power == 0;
+                        if show { char::from_digit(x / divisor % 10, 10).unwrap() } else { ' ' }
+                    })
+                    .collect();
+                writeln!(writer, "{:label_width$} {}", "", dim(line))?;
+            }
+        }
+
+        for y in min.y..=max.y {
+            let mut line = String::new();
+            if self.rulers {
+                line += &dim(format!("{y:>label_width$}"));
+                line.push(' ');
+            }
+            for x in min.x..=max.x {
+                let at = Point2::new(x, y);
+                let Some(value) = self.grid.get_ref(at) else {
+                    line.push(' ');
+                    continue;
+                };
+                let mut style = (self.style)(value);
+                if let Some(&bg) = self.highlights.get(&at) {
+                    style.bg = Some(bg);
+                }
+                push_cell(&mut line, style, color);
+            }
+            writeln!(writer, "{line}")?;
+        }
+        writer.flush()
+    }
+}
+
+fn push_cell(line: &mut String, style: CellStyle, color: bool) {
+    if !color || (style.fg.is_none() && style.bg.is_none()) {
+        line.push(style.ch);
+        return;
+    }
+    if let Some(Rgb(r, g, b)) = style.fg {
+        *line += &format!("\x1b[38;2;{r};{g};{b}m");
+    }
+    if let Some(Rgb(r, g, b)) = style.bg {
+        *line += &format!("\x1b[48;2;{r};{g};{b}m");
+    }
+    line.push(style.ch);
+    *line += "\x1b[0m";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, SparseGrid};
+
+    fn to_string<T, G: GridLike<T>, S: Fn(&T) -> CellStyle>(render: GridRender<'_, T, G, S>) -> String {
+        let mut out = Vec::new();
+        render.write(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_render() {
+        let grid = Grid::new(12, 2, '.');
+        assert_eq!(
+            to_string(grid.render(|&ch| ch.into()).rulers(true)),
+            "            11\n  \
+               012345678901\n\
+             0 ............\n\
+             1 ............\n"
+        );
+
+        let grid = Grid::from([['#', '.']]);
+        let red = Rgb(255, 0, 0);
+        let colored = to_string(grid.render(|&ch| match ch {
+            '#' => CellStyle::plain(ch).fg(red),
+            _ => ch.into(),
+        }).highlight([(1, 0)], Rgb::WHITE).color(true));
+        assert_eq!(colored, "\x1b[38;2;255;0;0m#\x1b[0m\x1b[48;2;255;255;255m.\x1b[0m\n");
+
+        let mut sparse = SparseGrid::new('.');
+        sparse.set((-1, 9), '#');
+        sparse.set((1, 10), '#');
+        assert_eq!(
+            to_string(sparse.render(|&ch| ch.into()).rulers(true)),
+            "   -  \n   101\n 9 #..\n10 ..#\n"
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{AocError, AocResult, BenchOptions, ElapsedStats, MeasureElapsed};
+use facet::Facet;
+use std::time::{Duration, Instant};
+
+/// Puzzle solution split into stages: the input is parsed once,
+/// then each part computes its answer from the parsed input.
+pub trait Solution {
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> AocResult<Self::Answer1>;
+    fn part2(&self, input: &Self::Input) -> AocResult<Self::Answer2>;
+}
+
+/// Value which can be reported as a puzzle answer.
+///
+/// Returns `None` if there is no answer to report (see [`Unsolved`]).
+pub trait Answer {
+    fn to_answer(&self) -> Option<String>;
+}
+
+macro_rules! impl_answer_for_display {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn to_answer(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+        })*
+    };
+}
+
+impl_answer_for_display!(i32, i64, i128, u32, u64, u128, usize, String);
+
+/// Answer for a part which does not have a solution yet.
+#[derive(Debug, PartialEq)]
+pub struct Unsolved;
+
+impl Answer for Unsolved {
+    fn to_answer(&self) -> Option<String> {
+        None
+    }
+}
+
+pub struct PartResult {
+    pub part: u32,
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+}
+
+pub struct SolutionRun {
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+#[derive(Facet)]
+pub struct StageStats {
+    pub stage: String,
+    pub elapsed: ElapsedStats,
+}
+
+/// Type-erased [`Solution`] to run any day through the same interface.
+pub trait RunSolution {
+    fn run(&self, input: &str, parts: &[u32]) -> AocResult<SolutionRun>;
+
+    /// Measures parse and each part stage separately over multiple runs.
+    fn bench(&self, input: &str, options: &BenchOptions) -> AocResult<Vec<StageStats>>;
+}
+
+impl<S: Solution> RunSolution for S {
+    fn run(&self, input: &str, parts: &[u32]) -> AocResult<SolutionRun> {
+        let start = Instant::now();
+        let parsed = self.parse(input)?;
+        let parse_elapsed = start.elapsed();
+
+        let mut results = Vec::new();
+        for &part in parts {
+            let start = Instant::now();
+            let answer = match part {
+                1 => self.part1(&parsed)?.to_answer(),
+                2 => self.part2(&parsed)?.to_answer(),
+                _ => Err(AocError::Invariant(format!("Unknown puzzle part: {part}")))?,
+            };
+            results.push(PartResult { part, answer, elapsed: start.elapsed() });
+        }
+
+        Ok(SolutionRun { parse_elapsed, parts: results })
+    }
+
+    fn bench(&self, input: &str, options: &BenchOptions) -> AocResult<Vec<StageStats>> {
+        let parsed = self.parse(input)?;
+        Ok(vec![
+            StageStats {
+                stage: "parse".into(),
+                elapsed: MeasureElapsed::benchmark(options, || self.parse(input))?,
+            },
+            StageStats {
+                stage: "part1".into(),
+                elapsed: MeasureElapsed::benchmark(options, || self.part1(&parsed))?,
+            },
+            StageStats {
+                stage: "part2".into(),
+                elapsed: MeasureElapsed::benchmark(options, || self.part2(&parsed))?,
+            },
+        ])
+    }
+}
@@ -0,0 +1,160 @@
+use crate::{Bounds, Grid, GridLike, Point2};
+use std::collections::HashMap;
+use std::ops::Index;
+
+/// Unbounded hash-backed grid where every cell not set explicitly has the default value.
+///
+/// Bounds grow to include each set cell and shrink when cells on the border are removed.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2, T>,
+    default: T,
+    bounds: Option<Bounds>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            default,
+            bounds: None,
+        }
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Count of explicitly set cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, at: impl Into<Point2>) -> bool {
+        self.cells.contains_key(&at.into())
+    }
+
+    /// Removes an explicitly set cell (so it has the default value again).
+    pub fn remove(&mut self, at: impl Into<Point2>) -> Option<T> {
+        let at = at.into();
+        let removed = self.cells.remove(&at)?;
+        if let Some(bounds) = self.bounds
+            && (at.x == bounds.min.x || at.x == bounds.max.x || at.y == bounds.min.y || at.y == bounds.max.y)
+        {
+            self.bounds = self.cells.keys().fold(None, |bounds: Option<Bounds>, &p| match bounds {
+                Some(mut bounds) => {
+                    bounds.include(p);
+                    Some(bounds)
+                }
+                None => Some(Bounds::at(p)),
+            });
+        }
+        Some(removed)
+    }
+
+    /// Iterates over explicitly set cells in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i32, i32), &T)> {
+        self.cells.iter().map(|(&at, value)| (at.into(), value))
+    }
+
+    fn include(&mut self, at: Point2) {
+        match &mut self.bounds {
+            Some(bounds) => bounds.include(at),
+            None => self.bounds = Some(Bounds::at(at)),
+        }
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// Converts cells within the bounds into a dense grid (with `(0, 0)` at the bounds minimum).
+    pub fn to_grid(&self) -> Grid<T> {
+        let Some(bounds) = self.bounds else {
+            return Grid::from_cells(Vec::new(), 0, 0);
+        };
+        let mut grid = Grid::new(bounds.width(), bounds.height(), self.default.clone());
+        for (&at, value) in &self.cells {
+            let offset = at - bounds.min;
+            grid.set((offset.x, offset.y), value.clone());
+        }
+        grid
+    }
+}
+
+impl<T: PartialEq> SparseGrid<T> {
+    /// Creates a sparse grid from the dense one storing only cells different from the default.
+    pub fn from_grid(grid: Grid<T>, default: T) -> SparseGrid<T> {
+        let mut sparse = SparseGrid::new(default);
+        let width = grid.width();
+        for (index, value) in grid.into_cells().into_iter().enumerate() {
+            if value != sparse.default {
+                let index = index as i32;
+                sparse.set((index % width, index / width), value);
+            }
+        }
+        sparse
+    }
+}
+
+impl<T> GridLike<T> for SparseGrid<T> {
+    fn bounds(&self) -> Option<Bounds> {
+        self.bounds
+    }
+
+    /// Returns the cell value or the default one (never `None`).
+    fn get_ref(&self, at: impl Into<Point2>) -> Option<&T> {
+        Some(self.cells.get(&at.into()).unwrap_or(&self.default))
+    }
+
+    fn set(&mut self, at: impl Into<Point2>, value: T) {
+        let at = at.into();
+        self.include(at);
+        self.cells.insert(at, value);
+    }
+}
+
+impl<T, P: Into<Point2>> Index<P> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, at: P) -> &T {
+        self.cells.get(&at.into()).unwrap_or(&self.default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ORTHOGONAL_NEIGHBORS;
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut sparse = SparseGrid::new('.');
+        sparse.set((-2, 5), '#');
+        sparse.set((3, -1), '@');
+        sparse.set((0, 0), '#');
+        assert_eq!(sparse.len(), 3);
+        assert_eq!(sparse[(1000, 1000)], '.');
+        assert_eq!(sparse.get((3, -1)), Some('@'));
+
+        let bounds = sparse.bounds().unwrap();
+        assert_eq!((bounds.min, bounds.max), (Point2::new(-2, -1), Point2::new(3, 5)));
+        assert_eq!(sparse.find(&'#'), Some((0, 0)));
+        assert_eq!(sparse.count_neighbors((0, 1), ORTHOGONAL_NEIGHBORS, |&c| c == '#'), 1);
+
+        let dense = sparse.to_grid();
+        assert_eq!((dense.width(), dense.height()), (6, 7));
+        assert_eq!(dense[(0, 6)], '#');
+        assert_eq!(dense.find(&'#'), Some((2, 1)));
+
+        assert_eq!(sparse.remove((-2, 5)), Some('#'));
+        let bounds = sparse.bounds().unwrap();
+        assert_eq!((bounds.min, bounds.max), (Point2::new(0, -1), Point2::new(3, 0)));
+
+        let round_trip = SparseGrid::from_grid(sparse.to_grid(), '.');
+        assert_eq!(round_trip.len(), 2);
+        assert_eq!(round_trip[(3, 0)], '@');
+    }
+}
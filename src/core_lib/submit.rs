@@ -0,0 +1,230 @@
+use crate::{AocClient, AocError, AocResult, read_json_file};
+use facet::Facet;
+use std::fs::write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Puzzle site response to a submitted answer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited { wait: Option<Duration> },
+    /// The part is already solved or not available yet.
+    WrongLevel,
+    Unknown(String),
+}
+
+impl Verdict {
+    /// Recognizes the verdict from the response page text.
+    pub fn parse(page: &str) -> Verdict {
+        let text = page.to_lowercase();
+        if text.contains("that's the right answer") {
+            Verdict::Correct
+        } else if text.contains("not the right answer") {
+            if text.contains("too high") {
+                Verdict::TooHigh
+            } else if text.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else if text.contains("answer too recently") {
+            Verdict::RateLimited { wait: parse_wait_time(&text) }
+        } else if text.contains("solving the right level") {
+            Verdict::WrongLevel
+        } else {
+            let article = text.split_once("<article>")
+                .and_then(|(_, rest)| rest.split_once("</article>"))
+                .map(|(article, _)| article)
+                .unwrap_or(page);
+            Verdict::Unknown(article.trim().chars().take(200).collect())
+        }
+    }
+
+    /// Returns the key to store the verdict in history if it is a final one.
+    fn history_key(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Correct => Some("correct"),
+            Verdict::TooHigh => Some("too high"),
+            Verdict::TooLow => Some("too low"),
+            Verdict::Wrong => Some("wrong"),
+            _ => None,
+        }
+    }
+}
+
+/// Parses wait time from text like "you have 1m 25s left to wait".
+fn parse_wait_time(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, time) = before.rsplit_once("you have ")?;
+    let mut seconds = 0;
+    for part in time.split_whitespace() {
+        let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+impl AocClient {
+    /// Posts the answer for a day part and returns the verdict.
+    pub fn submit_answer(&self, day: u32, part: u32, answer: &str) -> AocResult<Verdict> {
+        let page = self.post_form(
+            &format!("{}/day/{day}/answer", self.year()),
+            &[("level", &part.to_string()), ("answer", answer)],
+        )?;
+        Ok(Verdict::parse(&page))
+    }
+}
+
+/// Local history of submitted answers with their verdicts.
+pub struct SubmissionHistory {
+    path: PathBuf,
+    entries: Vec<Submission>,
+}
+
+#[derive(Facet, Clone)]
+pub struct Submission {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub verdict: String,
+}
+
+#[derive(Facet)]
+struct SubmissionsFile {
+    submissions: Vec<Submission>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SubmissionCheck {
+    Allowed,
+    AlreadyCorrect { answer: String },
+    KnownWrong { verdict: String },
+    /// Answer is outside of bounds (exclusive) from "too low" and "too high" verdicts.
+    OutOfBounds { low: Option<i128>, high: Option<i128> },
+}
+
+impl SubmissionHistory {
+    /// Loads history from a JSON file or starts an empty one if it does not exist.
+    pub fn load(path: impl AsRef<Path>) -> AocResult<SubmissionHistory> {
+        let path = path.as_ref().to_path_buf();
+        let entries = if path.exists() {
+            let file: SubmissionsFile = read_json_file(&path)?;
+            file.submissions
+        } else {
+            Vec::new()
+        };
+        Ok(SubmissionHistory { path, entries })
+    }
+
+    pub fn save(&self) -> AocResult<()> {
+        let content = facet_json::to_string_pretty(&SubmissionsFile {
+            submissions: self.entries.clone(),
+        }) + "\n";
+        write(&self.path, content).map_err(|err| AocError::io(&self.path, err))
+    }
+
+    pub fn submissions(&self, day: u32, part: u32) -> impl Iterator<Item = &Submission> {
+        self.entries.iter().filter(move |s| s.day == day && s.part == part)
+    }
+
+    /// Returns exclusive bounds for the answer from previous "too low" and "too high" verdicts.
+    pub fn bounds(&self, day: u32, part: u32) -> (Option<i128>, Option<i128>) {
+        let numeric = |verdict: &'static str| {
+            self.submissions(day, part)
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+        (numeric("too low").max(), numeric("too high").min())
+    }
+
+    /// Checks whether the answer is worth submitting.
+    pub fn check(&self, day: u32, part: u32, answer: &str) -> SubmissionCheck {
+        if let Some(correct) = self.submissions(day, part).find(|s| s.verdict == "correct") {
+            return SubmissionCheck::AlreadyCorrect { answer: correct.answer.clone() };
+        }
+        if let Some(known) = self.submissions(day, part).find(|s| s.answer == answer) {
+            return SubmissionCheck::KnownWrong { verdict: known.verdict.clone() };
+        }
+        let (low, high) = self.bounds(day, part);
+        if let Ok(value) = answer.parse::<i128>()
+            && (low.is_some_and(|low| value <= low) || high.is_some_and(|high| value >= high))
+        {
+            return SubmissionCheck::OutOfBounds { low, high };
+        }
+        return SubmissionCheck::Allowed;
+    }
+
+    /// Records the submission if the verdict is a final one, returns whether it was recorded.
+    pub fn record(&mut self, day: u32, part: u32, answer: &str, verdict: &Verdict) -> bool {
+        let Some(key) = verdict.history_key() else {
+            return false;
+        };
+        self.entries.push(Submission {
+            day,
+            part,
+            answer: answer.into(),
+            verdict: key.into(),
+        });
+        return true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::tests::{mock_server, test_client};
+
+    #[test]
+    fn test_parse_verdict() {
+        let page = "<main><article><p>That's not the right answer; your answer is too high. \
+            Please wait one minute.</p></article></main>";
+        assert_eq!(Verdict::parse(page), Verdict::TooHigh);
+        assert_eq!(
+            Verdict::parse("<article><p>You gave an answer too recently; \
+                You have 1m 5s left to wait.</p></article>"),
+            Verdict::RateLimited { wait: Some(Duration::from_secs(65)) }
+        );
+        assert_eq!(Verdict::parse("<article>That's the right answer!</article>"), Verdict::Correct);
+    }
+
+    #[test]
+    fn test_history_check() {
+        let mut history = SubmissionHistory::load("missing-submissions.json").unwrap();
+        history.record(1, 1, "100", &Verdict::TooHigh);
+        history.record(1, 1, "20", &Verdict::TooLow);
+        history.record(1, 1, "50", &Verdict::Wrong);
+        assert!(!history.record(1, 1, "60", &Verdict::RateLimited { wait: None }));
+
+        assert_eq!(history.check(1, 1, "50"), SubmissionCheck::KnownWrong { verdict: "wrong".into() });
+        assert_eq!(history.check(1, 1, "150"), SubmissionCheck::OutOfBounds { low: Some(20), high: Some(100) });
+        assert_eq!(history.check(1, 1, "20"), SubmissionCheck::KnownWrong { verdict: "too low".into() });
+        assert_eq!(history.check(1, 1, "60"), SubmissionCheck::Allowed);
+        assert_eq!(history.check(1, 2, "150"), SubmissionCheck::Allowed);
+
+        history.record(1, 1, "60", &Verdict::Correct);
+        assert_eq!(history.check(1, 1, "61"), SubmissionCheck::AlreadyCorrect { answer: "60".into() });
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, server) = mock_server(vec![
+            (200, "<article><p>That's not the right answer; your answer is too low.</p></article>"),
+        ]);
+        let client = test_client("submit", base_url);
+
+        assert_eq!(client.submit_answer(5, 2, "42").unwrap(), Verdict::TooLow);
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2025/day/5/answer "));
+        assert!(requests[0].ends_with("level=2&answer=42"));
+    }
+}
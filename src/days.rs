@@ -0,0 +1,67 @@
+use core_lib::{RunSolution, input_file};
+use crate::{puzzle01, puzzle02, puzzle03, puzzle04, puzzle05, puzzle06, puzzle07, puzzle08};
+use std::path::PathBuf;
+
+pub struct Day {
+    pub day: u32,
+    pub title: &'static str,
+    /// Creates the solution, optionally writing debug output files.
+    pub solution: fn(write_output: bool) -> Box<dyn RunSolution>,
+}
+
+impl Day {
+    /// Input file path relative to the data directory.
+    pub fn input_file(&self) -> PathBuf {
+        input_file(self.day)
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        title: "Secret Entrance",
+        solution: |_| Box::new(puzzle01::Puzzle01),
+    },
+    Day {
+        day: 2,
+        title: "Gift Shop",
+        solution: |_| Box::new(puzzle02::Puzzle02),
+    },
+    Day {
+        day: 3,
+        title: "Lobby",
+        solution: |_| Box::new(puzzle03::Puzzle03),
+    },
+    Day {
+        day: 4,
+        title: "Printing Department",
+        solution: |write_output| Box::new(puzzle04::Puzzle04 { write_output }),
+    },
+    Day {
+        day: 5,
+        title: "Cafeteria",
+        solution: |_| Box::new(puzzle05::Puzzle05),
+    },
+    Day {
+        day: 6,
+        title: "Trash Compactor",
+        solution: |_| Box::new(puzzle06::Puzzle06),
+    },
+    Day {
+        day: 7,
+        title: "Laboratories",
+        solution: |write_output| Box::new(puzzle07::Puzzle07 { write_output }),
+    },
+    Day {
+        day: 8,
+        title: "Playground",
+        solution: |write_output| Box::new(puzzle08::Puzzle08 {
+            connection_count: 1000,
+            write_output,
+        }),
+    },
+];
+
+pub fn find_day(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
@@ -0,0 +1,156 @@
+//! Advent of Code 2025 runner
+mod bench;
+mod cli;
+mod days;
+mod submit;
+mod verify;
+
+mod puzzle01;
+mod puzzle02;
+mod puzzle03;
+mod puzzle04;
+mod puzzle05;
+mod puzzle06;
+mod puzzle07;
+mod puzzle08;
+
+use bench::bench_days;
+use cli::{Cli, Command, DaySelection, USAGE};
+use core_lib::{
+    AnswerRegistry, AocClient, AocError, AocResult, BenchOptions, ClientConfig, DEFAULT_YEAR,
+    DataPaths, MeasureElapsed, configure_data_paths, data_paths, read_input_file,
+};
+use days::{DAYS, Day, find_day};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use submit::submit_day;
+use verify::verify_days;
+
+/// Answers registry file relative to the current directory (the repository root).
+const ANSWERS_FILE: &str = "answers.json";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let cli = match Cli::parse(&args) {
+        Ok(cli) => cli,
+        Err(err) => {
+            eprintln!("{err}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let configured = DataPaths::resolve(DEFAULT_YEAR, cli.data_dir)
+        .and_then(configure_data_paths);
+    if let Err(err) = configured {
+        eprintln!("Error: {err}");
+        return ExitCode::FAILURE;
+    }
+
+    match execute(cli.command) {
+        Ok(code) => code,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Executes the command and returns failure exit code if it completed
+/// with a negative outcome (e.g. answer was rejected).
+fn execute(command: Command) -> AocResult<ExitCode> {
+    match command {
+        Command::Run { days, part } => {
+            for day in select_days(days)? {
+                run_day(day, part)?;
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Download { days } => {
+            let client = AocClient::new(ClientConfig::resolve()?, data_paths().clone());
+            let days = match days {
+                DaySelection::All => DAYS.iter().map(|day| day.day).collect(),
+                DaySelection::Single(day) if (1..=25).contains(&day) => vec![day],
+                DaySelection::Single(day) => Err(AocError::Usage(format!("Invalid day: {day}")))?,
+            };
+            for day in days {
+                let path = client.fetch_input(day)?;
+                println!("Day {day}: {}", path.display());
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Submit { day, part } => {
+            let day = require_day(day)?;
+            let client = AocClient::new(ClientConfig::resolve()?, data_paths().clone());
+            let accepted = submit_day(day, part, &client, &mut load_answers()?)?;
+            Ok(if accepted { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+        }
+        Command::Verify { days, record } => {
+            let mut registry = load_answers()?;
+            let passed = verify_days(&select_days(days)?, &mut registry, record)?;
+            Ok(if passed { ExitCode::SUCCESS } else { ExitCode::FAILURE })
+        }
+        Command::Bench { days, warmup, samples, json } => {
+            bench_days(&select_days(days)?, &BenchOptions { warmup, samples }, json)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::List => {
+            for day in DAYS {
+                println!("Day {}: {}", day.day, day.title);
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+    }
+}
+
+fn load_answers() -> AocResult<AnswerRegistry> {
+    AnswerRegistry::load(ANSWERS_FILE)
+}
+
+fn select_days(days: DaySelection) -> AocResult<Vec<&'static Day>> {
+    match days {
+        DaySelection::All => Ok(DAYS.iter().collect()),
+        DaySelection::Single(day) => Ok(vec![require_day(day)?]),
+    }
+}
+
+fn require_day(day: u32) -> AocResult<&'static Day> {
+    find_day(day).ok_or_else(|| AocError::Usage(format!("Day {day} is not implemented")))
+}
+
+fn run_day(day: &Day, part: Option<u32>) -> AocResult<()> {
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    println!("Day {}: {}", day.day, day.title);
+
+    let mut time = MeasureElapsed::start();
+    let input_path = find_or_fetch_input(day)?;
+    let input = read_input_file(&input_path)?;
+    time.print_measured("[read]");
+
+    let run = (day.solution)(true).run(&input, &parts)
+        .map_err(|err| err.with_file(&input_path))?;
+    println!("[parse] elapsed time: {:.2?}", run.parse_elapsed);
+    for result in run.parts {
+        let answer = result.answer.as_deref().unwrap_or("(unsolved)");
+        println!("Part {}: {answer}", result.part);
+        println!("[part{}] elapsed time: {:.2?}", result.part, result.elapsed);
+    }
+    Ok(())
+}
+
+/// Finds the day input or downloads it if a session token is configured.
+fn find_or_fetch_input(day: &Day) -> AocResult<PathBuf> {
+    match data_paths().find(day.input_file()) {
+        Ok(path) => Ok(path),
+        Err(err) => {
+            let client = AocClient::new(ClientConfig::resolve()?, data_paths().clone());
+            if !client.has_session() {
+                Err(err)?;
+            }
+            client.fetch_input(day.day)
+        }
+    }
+}
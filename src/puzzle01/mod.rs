@@ -0,0 +1,129 @@
+//! Day 1: Secret Entrance
+use core_lib::{AocError, AocResult, ParseError, Solution};
+use regex::Regex;
+
+pub struct Puzzle01;
+
+impl Solution for Puzzle01 {
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        let parser = RotationParser::new()?;
+        let rotations = input.lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| parser.parse(input, line))
+            .collect::<Result<Vec<i32>, ParseError>>()?;
+        Ok(rotations)
+    }
+
+    fn part1(&self, rotations: &Self::Input) -> AocResult<Self::Answer1> {
+        let mut position = 50;
+        let mut secret_code = 0;
+        for rotation in rotations {
+            position += rotation;
+            position = position.rem_euclid(100);
+            if position == 0 {
+                secret_code += 1;
+            }
+        }
+
+        return Ok(secret_code);
+    }
+
+    /// Computes secret code by CLICK method.
+    fn part2(&self, rotations: &Self::Input) -> AocResult<Self::Answer2> {
+        let debug = std::env::var("DEBUG").is_ok();
+
+        let mut position = 50;
+        let mut secret_code = 0;
+        for &rotation in rotations {
+            if rotation == 0 {
+                continue;
+            }
+
+            let before = position;
+            position += rotation;
+
+            let mut whole_rotations = position.div_euclid(100).abs();
+            if rotation < 0 && before == 0 {
+                whole_rotations -= 1;
+            }
+
+            secret_code += whole_rotations;
+            position = position.rem_euclid(100);
+
+            if debug {
+                let sign = if rotation > 0 { "+" } else { "" };
+                println!("Rotate {sign}{rotation}: {before} -> {position}");
+                if whole_rotations > 0 {
+                    println!("Click {whole_rotations} times");
+                }
+            }
+
+            if position == 0 && rotation < 0 {
+                secret_code += 1;
+                if debug {
+                    println!("Click once from stopping at zero");
+                }
+            }
+        }
+
+        return Ok(secret_code);
+    }
+}
+
+struct RotationParser {
+    regex: Regex,
+}
+
+impl RotationParser {
+    fn new() -> AocResult<RotationParser> {
+        let regex = Regex::new(r"^(L|R)(\d+)$")
+            .map_err(|err| AocError::Invariant(format!("Failed to construct regex to parse: {err}")))?;
+        Ok(RotationParser { regex })
+    }
+
+    fn parse(&self, input: &str, line: &str) -> Result<i32, ParseError> {
+        let (_, [direction, count]) = self.regex
+            .captures(line)
+            .map(|c| c.extract())
+            .ok_or_else(|| ParseError::at(input, line, "Failed to parse rotation"))?;
+
+        let count: i32 = count.parse()
+            .map_err(|_| ParseError::at(input, count, "Failed to parse rotation count"))?;
+
+        match direction {
+            "L" => Ok(-count),
+            "R" => Ok(count),
+            _ => Err(ParseError::at(input, direction, "Unexpected rotation direction")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+";
+
+    #[test]
+    fn test_example() {
+        let solution = Puzzle01;
+        let input = solution.parse(EXAMPLE).unwrap();
+        assert_eq!(solution.part1(&input).unwrap(), 3);
+        assert_eq!(solution.part2(&input).unwrap(), 6);
+    }
+}
@@ -0,0 +1,227 @@
+//! Day 2: Gift Shop
+use core_lib::{AocResult, ParseError, Solution, Span};
+use std::ops::RangeInclusive;
+
+pub struct Puzzle02;
+
+impl Solution for Puzzle02 {
+    type Input = Vec<RangeInclusive<i64>>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        Ok(parse_ranges(input)?)
+    }
+
+    /// Computes total of invalid IDs.
+    fn part1(&self, ranges: &Self::Input) -> AocResult<Self::Answer1> {
+        let debug = std::env::var("DEBUG").is_ok();
+
+        let mut total = 0;
+        for range in ranges {
+            let even_ranges: Vec<RangeInclusive<i64>> = split_range_by_digit_count(range.clone())
+                .into_iter()
+                .filter(|r| digit_count(*r.start()).is_multiple_of(2))
+                .collect();
+            if debug {
+                eprintln!("Split {:?} into even ranges: {:?}", range, even_ranges);
+            }
+
+            for sub_range in even_ranges {
+                let (ah, al) = split_in_half(*sub_range.start());
+                let (bh, bl) = split_in_half(*sub_range.end());
+
+                if ah >= al && (ah < bh || ah <= bl) {
+                    let id = merge_from_halves(ah, ah);
+                    total += id;
+                    if debug {
+                        eprintln!(" - invalid ID: {}", id);
+                    }
+                }
+                if bh > ah && bl >= bh {
+                    let id = merge_from_halves(bh, bh);
+                    total += id;
+                    if debug {
+                        eprintln!(" - invalid ID: {}", id);
+                    }
+                }
+                for i in (ah + 1)..=(bh - 1) {
+                    let id = merge_from_halves(i, i);
+                    total += id;
+                    if debug {
+                        eprintln!(" - invalid ID: {}", id);
+                    }
+                }
+            }
+        }
+
+        return Ok(total);
+    }
+
+    /// Computes total of multi-duplicated invalid IDs.
+    fn part2(&self, ranges: &Self::Input) -> AocResult<Self::Answer2> {
+        let debug = std::env::var("DEBUG").is_ok();
+
+        let mut total = 0;
+        for range in ranges {
+            let sub_ranges: Vec<RangeInclusive<i64>> = split_range_by_digit_count(range.clone());
+            if debug {
+                eprintln!("Split {:?} into sub ranges: {:?}", range, sub_ranges);
+            }
+
+            for sub_range in sub_ranges {
+                let digits = digit_count(*sub_range.start());
+                for id in sub_range {
+                    if can_construct_from_duplicated_part(id, digits) {
+                        total += id;
+                        if debug {
+                            eprintln!(" - invalid ID: {}", id);
+                        }
+                    }
+                }
+            }
+        }
+
+        return Ok(total);
+    }
+}
+
+fn parse_ranges(input: &str) -> Result<Vec<RangeInclusive<i64>>, ParseError> {
+    Span::new(input).trim().list(",", |range| range.range("-"))
+}
+
+fn split_range_by_digit_count(range: RangeInclusive<i64>) -> Vec<RangeInclusive<i64>> {
+    let mut sub_ranges = vec![];
+    let mut rest = range;
+    let mut start_digits = digit_count(*rest.start());
+    let end_digits = digit_count(*rest.end());
+    while start_digits < end_digits {
+        let start = *rest.start();
+        let next_start = 10_i64.pow(start_digits);
+        sub_ranges.push(start..=(next_start - 1));
+        rest = next_start..=(*rest.end());
+        start_digits += 1;
+    }
+    sub_ranges.push(rest);
+    return sub_ranges;
+}
+
+fn digit_count(n: i64) -> u32 {
+    if n < 0 {
+        return 0;
+    } else if n == 0 {
+        return 1;
+    }
+    let mut x = n;
+    let mut count = 0;
+    while x > 0 {
+        x /= 10;
+        count += 1;
+    }
+    return count;
+}
+
+fn split_in_half(n: i64) -> (i64, i64) {
+    let count = digit_count(n);
+    let shift = 10_i64.pow(count / 2);
+    (n / shift, n % shift)
+}
+
+fn merge_from_halves(high: i64, low: i64) -> i64 {
+    let count = digit_count(low);
+    let shift = 10_i64.pow(count);
+    high * shift + low
+}
+
+fn can_construct_from_duplicated_part(n: i64, digits: u32) -> bool {
+    'next_size: for part_size in 1..=(digits / 2) {
+        if digits.is_multiple_of(part_size) {
+            let shift = 10_i64.pow(part_size);
+            let low = n % shift;
+            let mut rest = n / shift;
+            while rest > 0 {
+                if rest % shift == low {
+                    rest /= shift;
+                } else {
+                    continue 'next_size;
+                }
+            }
+            return true;
+        }
+    }
+    return false;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    const EXAMPLE: &str = "\
+11-22,95-115,998-1012,1188511880-1188511890,222220-222224,\
+1698522-1698528,446443-446449,38593856-38593862,565653-565659,\
+824824821-824824827,2121212118-2121212124
+";
+
+    #[test]
+    fn test_example() {
+        let input = Puzzle02.parse(EXAMPLE).unwrap();
+        assert_eq!(Puzzle02.part1(&input).unwrap(), 1227775554);
+        assert_eq!(Puzzle02.part2(&input).unwrap(), 4174379265);
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digit_count(-12), 0);
+        assert_eq!(digit_count(0), 1);
+        assert_eq!(digit_count(1), 1);
+        assert_eq!(digit_count(5), 1);
+        assert_eq!(digit_count(12), 2);
+        assert_eq!(digit_count(347), 3);
+        assert_eq!(digit_count(5000), 4);
+        assert_eq!(digit_count(123456789), 9);
+    }
+
+    #[test]
+    fn test_large_range() {
+        assert_eq!(total_generated_invalid_ids(1..=2_i64.pow(32)), 88304989965662);
+
+        let small_range = 1..=1_000_000;
+        let expected = Puzzle02.part2(&vec![small_range.clone()]).unwrap();
+        assert_eq!(total_generated_invalid_ids(small_range), expected as i128);
+    }
+
+    /// Computes total of multi-duplicated invalid IDs in a range
+    /// by generating candidate IDs instead of checking each one.
+    fn total_generated_invalid_ids(range: RangeInclusive<i64>) -> i128 {
+        let mut invalid_ids = HashSet::new();
+        for sub_range in split_range_by_digit_count(range) {
+            let digits = digit_count(*sub_range.start());
+
+            for part_size in 1..=(digits / 2) {
+                if digits.is_multiple_of(part_size) {
+                    let part_count = digits / part_size;
+                    let min = 10_i64.pow(part_size - 1);
+                    let max = 10_i64.pow(part_size);
+                    for part in min..max {
+                        let mut id = part;
+                        for _ in 1..part_count {
+                            id *= max;
+                            id += part;
+                        }
+                        if sub_range.contains(&id) {
+                            invalid_ids.insert(id);
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut total: i128 = 0;
+        for id in invalid_ids {
+            total += id as i128;
+        }
+
+        return total;
+    }
+}
@@ -0,0 +1,111 @@
+//! Day 3: Lobby
+use core_lib::{AocError, AocResult, ParseError, Solution, Span};
+
+pub struct Puzzle03;
+
+impl Solution for Puzzle03 {
+    type Input = Vec<BatteryBank>;
+    type Answer1 = i32;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        Ok(parse_battery_banks(input)?)
+    }
+
+    /// Computes total max joltage with 2 batteries per bank.
+    fn part1(&self, battery_banks: &Self::Input) -> AocResult<Self::Answer1> {
+        let mut total_joltage = 0;
+        for bank in battery_banks {
+            check_bank_size(bank, 2)?;
+            let a = *bank.iter().take(bank.len() - 1).max().unwrap();
+            let a_index = bank.iter().position(|&v| v == a).unwrap();
+            let b = *bank.iter().skip(a_index + 1).max().unwrap();
+            let joltage = a * 10 + b;
+            total_joltage += joltage;
+        }
+
+        return Ok(total_joltage);
+    }
+
+    /// Computes total max joltage with 12 batteries per bank.
+    fn part2(&self, battery_banks: &Self::Input) -> AocResult<Self::Answer2> {
+        let debug = std::env::var("DEBUG").is_ok();
+        let select_count = 12;
+
+        let mut total_joltage: i64 = 0;
+        for bank in battery_banks {
+            check_bank_size(bank, select_count)?;
+            let mut joltage: i64 = 0;
+            let mut from_index = 0;
+
+            if debug {
+                eprintln!(" - {}", bank.iter().map(|v| char::from_digit(*v as u32, 10).unwrap()).collect::<String>());
+                eprint!("   ");
+            }
+
+            for i in 0..select_count {
+                let take_count = bank.len() - from_index - (select_count - i - 1);
+                let max = *bank.iter()
+                    .skip(from_index)
+                    .take(take_count)
+                    .max()
+                    .unwrap();
+                let max_offset = bank.iter()
+                    .skip(from_index)
+                    .take(take_count)
+                    .position(|&v| v == max)
+                    .unwrap();
+
+                if debug {
+                    eprint!("{}^", ".".repeat(max_offset));
+                }
+
+                joltage = joltage * 10 + (max as i64);
+                from_index += max_offset + 1;
+            }
+
+            if debug {
+                eprintln!("{}", ".".repeat(bank.len() - from_index));
+            }
+
+            total_joltage += joltage;
+        }
+
+        return Ok(total_joltage);
+    }
+}
+
+type BatteryBank = Vec<i32>;
+
+fn check_bank_size(bank: &BatteryBank, select_count: usize) -> AocResult<()> {
+    if bank.len() < select_count {
+        Err(AocError::Invariant(format!(
+            "Battery bank has {} batteries but {select_count} are required", bank.len()
+        )))?;
+    }
+    Ok(())
+}
+
+fn parse_battery_banks(input: &str) -> Result<Vec<BatteryBank>, ParseError> {
+    Span::new(input).map_lines(|line| line.digits())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+";
+
+    #[test]
+    fn test_example() {
+        let solution = Puzzle03;
+        let input = solution.parse(EXAMPLE).unwrap();
+        assert_eq!(solution.part1(&input).unwrap(), 357);
+        assert_eq!(solution.part2(&input).unwrap(), 3121910778619);
+    }
+}
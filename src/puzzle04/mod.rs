@@ -0,0 +1,174 @@
+//! Day 4: Printing Department
+use core_lib::{
+    AocError, AocResult, Automaton, GifWriter, Grid, GridRecording, MOORE_NEIGHBORS, Rgb,
+    Solution, create_output_file, get_data_path,
+};
+use std::io::Write;
+use std::time::Duration;
+
+/// Pixel size of a grid cell in exported images.
+const IMAGE_SCALE: u32 = 4;
+
+pub struct Puzzle04 {
+    pub write_output: bool,
+}
+
+impl Solution for Puzzle04 {
+    type Input = Grid<Tile>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        Ok(Grid::parse(input, Tile::parse)?)
+    }
+
+    /// Computes total accessible paper rolls.
+    fn part1(&self, grid: &Self::Input) -> AocResult<Self::Answer1> {
+        let mut automaton = Automaton::new(grid.clone(), remove_paper_rolls);
+        automaton.step();
+        let total_accessible = count_removed(automaton.grid());
+
+        if self.write_output {
+            let path = get_data_path("output/puzzle04_accessible.txt");
+            let mut writer = create_output_file(&path)?;
+            automaton.grid().map(Tile::as_char).write_into(&mut writer)
+                .map_err(|err| AocError::io(&path, err))?;
+        }
+
+        return Ok(total_accessible);
+    }
+
+    /// Computes total removable paper rolls.
+    fn part2(&self, grid: &Self::Input) -> AocResult<Self::Answer2> {
+        let mut total_removed = 0;
+
+        let path = get_data_path("output/puzzle04_removed_by_step.txt");
+        let mut writer = if self.write_output {
+            Some(create_output_file(&path)?)
+        } else {
+            None
+        };
+        let gif_path = get_data_path("output/puzzle04_removed_by_step.gif");
+        let mut gif = if self.write_output {
+            let mut gif = GifWriter::create(
+                &gif_path,
+                grid.width() as u32 * IMAGE_SCALE,
+                grid.height() as u32 * IMAGE_SCALE,
+                Duration::from_millis(200),
+            )?;
+            gif.write_frame(&grid.to_image(IMAGE_SCALE, Tile::color))
+                .map_err(|err| AocError::io(&gif_path, err))?;
+            Some(gif)
+        } else {
+            None
+        };
+        let mut recording = self.write_output
+            .then(|| GridRecording::new(grid, Tile::as_char));
+
+        let mut automaton = Automaton::new(grid.clone(), remove_paper_rolls);
+        automaton.run_until_stable(|generation| {
+            let removed_at_step = count_removed(generation.grid);
+            total_removed += removed_at_step;
+
+            // Last generation with changes only clears removed marks
+            if let Some(writer) = &mut writer && removed_at_step > 0 {
+                let roll_word = if removed_at_step == 1 { "roll" } else { "rolls" };
+                writeln!(writer, "Removed {removed_at_step} {roll_word} of paper:")
+                    .and_then(|_| generation.grid.map(Tile::as_char).write_into(writer))
+                    .and_then(|_| writeln!(writer))
+                    .map_err(|err| AocError::io(&path, err))?;
+            }
+            if let Some(gif) = &mut gif {
+                gif.write_frame(&generation.grid.to_image(IMAGE_SCALE, Tile::color))
+                    .map_err(|err| AocError::io(&gif_path, err))?;
+            }
+            if let Some(recording) = &mut recording {
+                recording.push(generation.grid)?;
+            }
+            Ok::<_, AocError>(())
+        })?;
+        if let Some(gif) = gif {
+            gif.finish().map_err(|err| AocError::io(&gif_path, err))?;
+        }
+        if let Some(recording) = recording {
+            recording.save(get_data_path("output/puzzle04_frames.json"))?;
+        }
+
+        return Ok(total_removed);
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tile {
+    Empty,
+    Paper,
+    /// Paper roll removed at the current step (cleared at the next one).
+    Removed,
+}
+
+impl Tile {
+    fn parse(ch: char) -> Result<Tile, String> {
+        match ch {
+            '.' => Ok(Tile::Empty),
+            '@' => Ok(Tile::Paper),
+            _ => Err(format!("expected '.' or '@', got '{ch}'")),
+        }
+    }
+
+    fn as_char(&self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::Paper => '@',
+            Tile::Removed => 'x',
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Tile::Empty => Rgb(24, 24, 32),
+            Tile::Paper => Rgb(230, 220, 190),
+            Tile::Removed => Rgb(220, 60, 50),
+        }
+    }
+}
+
+/// Marks accessible paper rolls as removed and clears ones removed at the previous step.
+fn remove_paper_rolls(grid: &Grid<Tile>, at: (i32, i32), &tile: &Tile) -> Tile {
+    match tile {
+        Tile::Paper if grid.count_neighbors(at, MOORE_NEIGHBORS, |&t| t == Tile::Paper) < 4 => {
+            Tile::Removed
+        }
+        Tile::Removed => Tile::Empty,
+        _ => tile,
+    }
+}
+
+fn count_removed(grid: &Grid<Tile>) -> i32 {
+    grid.count(&Tile::Removed) as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+    #[test]
+    fn test_example() {
+        let solution = Puzzle04 { write_output: false };
+        let input = solution.parse(EXAMPLE).unwrap();
+        assert_eq!(solution.part1(&input).unwrap(), 13);
+        assert_eq!(solution.part2(&input).unwrap(), 43);
+    }
+}
@@ -0,0 +1,82 @@
+//! Day 5: Cafeteria
+use core_lib::{AocResult, ParseError, RangeSet, Solution, Span};
+
+pub struct Puzzle05;
+
+impl Solution for Puzzle05 {
+    type Input = Database;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        Ok(Database::parse(input)?)
+    }
+
+    /// Computes fresh ingredient ID count.
+    fn part1(&self, db: &Self::Input) -> AocResult<Self::Answer1> {
+        let mut fresh_count = 0;
+        for &id in &db.available_ids {
+            if db.fresh_ranges.find_range(id).is_some() {
+                fresh_count += 1;
+            }
+        }
+
+        return Ok(fresh_count);
+    }
+
+    /// Computes fresh ingredient total range length.
+    fn part2(&self, db: &Self::Input) -> AocResult<Self::Answer2> {
+        let fresh_total_count = db.fresh_ranges.ranges()
+            .iter().fold(0, |acc, r| acc + (*r.end() - *r.start() + 1));
+
+        return Ok(fresh_total_count);
+    }
+}
+
+pub struct Database {
+    fresh_ranges: RangeSet<i64>,
+    available_ids: Vec<i64>,
+}
+
+impl Database {
+    fn parse(input: &str) -> Result<Database, ParseError> {
+        let mut sections = Span::new(input).sections();
+        let ranges = match sections.next() {
+            Some(section) => section.map_lines(|line| line.range("-"))?,
+            None => vec![],
+        };
+        let available_ids = match sections.next() {
+            Some(section) => section.map_lines(|line| line.parse())?,
+            None => vec![],
+        };
+
+        Ok(Database { fresh_ranges: RangeSet::new(&ranges), available_ids })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+";
+
+    #[test]
+    fn test_example() {
+        let solution = Puzzle05;
+        let input = solution.parse(EXAMPLE).unwrap();
+        assert_eq!(solution.part1(&input).unwrap(), 3);
+        assert_eq!(solution.part2(&input).unwrap(), 14);
+    }
+}
@@ -0,0 +1,178 @@
+//! Day 6: Trash Compactor
+use core_lib::{AocResult, Grid, ParseError, Solution};
+
+pub struct Puzzle06;
+
+/// Worksheet problems read both row-wise (normal) and column-wise (cephalopod).
+pub struct Worksheet {
+    normal: Vec<Problem>,
+    cephalopod: Vec<Problem>,
+}
+
+impl Solution for Puzzle06 {
+    type Input = Worksheet;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        Ok(Worksheet {
+            normal: parse_problems_normal(input)?,
+            cephalopod: parse_problems_cephalopod(input)?,
+        })
+    }
+
+    /// Computes total of normal problem results.
+    fn part1(&self, worksheet: &Self::Input) -> AocResult<Self::Answer1> {
+        let mut total = 0;
+        for problem in &worksheet.normal {
+            total += problem.compute();
+        }
+
+        return Ok(total);
+    }
+
+    /// Computes total of cephalopod problem results.
+    fn part2(&self, worksheet: &Self::Input) -> AocResult<Self::Answer2> {
+        let mut total = 0;
+        for problem in &worksheet.cephalopod {
+            total += problem.compute();
+        }
+
+        return Ok(total);
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Operator {
+    Add,
+    Multiply,
+}
+
+impl Operator {
+    fn parse(str: &str) -> Option<Operator> {
+        match str {
+            "+" => Some(Operator::Add),
+            "*" => Some(Operator::Multiply),
+            _ => None
+        }
+    }
+}
+
+struct Problem {
+    numbers: Vec<i64>,
+    op: Operator,
+}
+
+impl Problem {
+    fn compute(&self) -> i64 {
+        match self.op {
+            Operator::Add => self.numbers.iter().sum(),
+            Operator::Multiply => self.numbers.iter().product(),
+        }
+    }
+}
+
+fn parse_problems_normal(input: &str) -> Result<Vec<Problem>, ParseError> {
+    let mut rows: Vec<(&str, Vec<i64>)> = Vec::new();
+    let mut operators: Vec<Operator> = Vec::new();
+    for line in input.lines() {
+        if line.is_empty() {
+            break;
+        }
+        let items: Vec<_> = line.split_whitespace().collect();
+        if let Some(first) = items.first() && Operator::parse(first).is_some() {
+            operators = items.into_iter()
+                .map(|item| Operator::parse(item)
+                    .ok_or_else(|| ParseError::at(input, item, "Failed to parse operator")))
+                .collect::<Result<Vec<_>, _>>()?;
+        } else {
+            let numbers = items.into_iter()
+                .map(|item| item.parse::<i64>()
+                    .map_err(|_| ParseError::at(input, item, "Failed to parse number")))
+                .collect::<Result<Vec<_>, _>>()?;
+            rows.push((line, numbers));
+        }
+    }
+
+    let mut problems = vec![];
+    for (i, &op) in operators.iter().enumerate() {
+        let numbers = rows.iter()
+            .map(|(line, row)| row.get(i).copied().ok_or_else(|| {
+                ParseError::at(input, &line[line.len()..], "Numbers row is shorter than operators row")
+            }))
+            .collect::<Result<Vec<_>, _>>()?;
+        problems.push(Problem { numbers, op });
+    }
+
+    Ok(problems)
+}
+
+fn parse_problems_cephalopod(input: &str) -> Result<Vec<Problem>, ParseError> {
+    let mut rows: Vec<&str> = Vec::new();
+    let mut operators: Vec<Operator> = Vec::new();
+    for line in input.lines() {
+        if line.is_empty() {
+            break;
+        }
+        let items: Vec<_> = line.split_whitespace().collect();
+        if let Some(first) = items.first() && Operator::parse(first).is_some() {
+            operators = items.into_iter()
+                .map(|item| Operator::parse(item)
+                    .ok_or_else(|| ParseError::at(input, item, "Failed to parse operator")))
+                .collect::<Result<Vec<_>, _>>()?;
+        } else {
+            rows.push(line);
+        }
+    }
+
+    // Rows may have trailing spaces trimmed so pad them to read full columns
+    let sheet = Grid::from_lines_padded(&rows, ' ');
+
+    let mut groups: Vec<Vec<i64>> = Vec::new();
+    let mut current_group: Vec<i64> = Vec::new();
+    let column_count = sheet.width();
+    for (i, column) in sheet.columns().enumerate() {
+        let column: Vec<_> = column
+            .filter_map(|ch| ch.to_digit(10).map(|d| d as i64))
+            .collect();
+
+        let is_last = (i as i32 + 1) == column_count;
+        if !column.is_empty() {
+            let number = column.iter().fold(0, |acc, digit| acc * 10 + digit);
+            current_group.push(number);
+        }
+
+        if (column.is_empty() || is_last) && !current_group.is_empty() {
+            groups.push(current_group.into_iter().collect());
+            current_group = vec![];
+        }
+    }
+
+    let problems = operators.into_iter()
+        .zip(groups)
+        .map(|(op, numbers)| Problem { numbers, op })
+        .collect();
+
+    Ok(problems)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Trailing spaces are significant for column-wise reading
+    const EXAMPLE: &str = concat!(
+        "123 328  51 64 \n",
+        " 45 64  387 23 \n",
+        "  6 98  215 314\n",
+        "*   +   *   +  \n",
+    );
+
+    #[test]
+    fn test_example() {
+        let solution = Puzzle06;
+        let input = solution.parse(EXAMPLE).unwrap();
+        assert_eq!(solution.part1(&input).unwrap(), 4277556);
+        assert_eq!(solution.part2(&input).unwrap(), 3263827);
+    }
+}
@@ -0,0 +1,254 @@
+//! Day 7: Laboratories
+use core_lib::{
+    AocError, AocResult, Direction, Grid, GridRecording, Point2, Rgb, Solution,
+    create_output_file, get_data_path,
+};
+
+pub struct Puzzle07 {
+    pub write_output: bool,
+}
+
+impl Solution for Puzzle07 {
+    type Input = Grid<Cell>;
+    type Answer1 = i32;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        Ok(Grid::parse(input, Cell::parse)?)
+    }
+
+    /// Computes beam split count.
+    fn part1(&self, input: &Self::Input) -> AocResult<Self::Answer1> {
+        let mut grid = input.clone();
+        let mut recording = self.write_output
+            .then(|| GridRecording::new(&grid, Cell::as_char));
+        propagate_beams_fully(&mut grid, |grid| {
+            if let Some(recording) = &mut recording {
+                recording.push(grid)?;
+            }
+            Ok::<_, AocError>(())
+        })?;
+        if let Some(recording) = recording {
+            recording.save(get_data_path("output/puzzle07_frames.json"))?;
+        }
+
+        if self.write_output {
+            let path = get_data_path("output/puzzle07_beams.txt");
+            let mut writer = create_output_file(&path)?;
+            grid.map(Cell::as_char).write_into(&mut writer)
+                .map_err(|err| AocError::io(&path, err))?;
+        }
+
+        let mut beam_split_count = 0;
+        for j in 0..grid.height() {
+            for i in 0..grid.width() {
+                if let Some(Cell::Splitter) = grid.get((i, j)) && let Some(Cell::Beam) = grid.get((i, j - 1)) {
+                    beam_split_count += 1;
+                }
+            }
+        }
+
+        return Ok(beam_split_count);
+    }
+
+    /// Computes quantum tachyon path count.
+    fn part2(&self, input: &Self::Input) -> AocResult<Self::Answer2> {
+        let mut grid = input.clone();
+        propagate_beams_fully(&mut grid, |_| Ok::<_, AocError>(()))?;
+
+        let mut path_count = Grid::new(grid.width(), grid.height(), 0i64);
+        for i in 0..grid.width() {
+            if let Some(Cell::Beam) = grid.get((i, grid.height() - 1)) {
+                path_count.set((i, grid.height() - 1), 1);
+            }
+        }
+
+        for j in (0..grid.height() - 1).rev() {
+            for i in 0..grid.width() {
+                match grid.get((i, j)) {
+                    Some(Cell::Splitter) => {
+                        let count = path_count.neighbors((i, j), SPLIT_PATHS)
+                            .map(|(_, &count)| count)
+                            .sum();
+                        path_count.set((i, j), count);
+                    }
+                    Some(Cell::Beam) | Some(Cell::Start) => {
+                        if let Some(count) = path_count.get((i, j + 1)) {
+                            path_count.set((i, j), count);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let start = grid.find(&Cell::Start)
+            .ok_or_else(|| AocError::Invariant("Failed to find starting point S".into()))?;
+        let total_path_count = path_count.get(start).unwrap_or(0);
+
+        if self.write_output {
+            let mut gradient = grid.map(Cell::as_char);
+            for j in 0..grid.height() {
+                for i in 0..grid.width() {
+                    if let Some(Cell::Beam) = grid.get((i, j)) && let Some(count) = path_count.get((i, j)) {
+                        let value = count.to_string().len().clamp(0, 15) as u32;
+                        gradient.set((i, j), char::from_digit(value, 16).unwrap_or('?'));
+                    }
+                }
+            }
+            let path = get_data_path("output/puzzle07_path_count.txt");
+            let mut writer = create_output_file(&path)?;
+            gradient.write_into(&mut writer)
+                .map_err(|err| AocError::io(&path, err))?;
+
+            let max_count = path_count.iter().map(|(_, &count)| count).max().unwrap_or(0);
+            let palette = PathCountPalette::new(max_count);
+            let mut counted = Grid::new(grid.width(), grid.height(), (Cell::Empty, 0));
+            for (at, &cell) in grid.iter() {
+                counted.set(at, (cell, path_count.get(at).unwrap_or(0)));
+            }
+            counted.to_image(IMAGE_SCALE, |&(cell, count)| palette.color(cell, count))
+                .save(get_data_path("output/puzzle07_path_count.png"))?;
+        }
+
+        return Ok(total_path_count);
+    }
+}
+
+/// Pixel size of a grid cell in exported images.
+const IMAGE_SCALE: u32 = 4;
+
+/// Cells to the sides of a splitter where the beam continues.
+const SPLIT_BEAMS: &[Direction] = &[Direction::W, Direction::E];
+
+/// Cells below the sides of a splitter where the split paths continue.
+const SPLIT_PATHS: &[Direction] = &[Direction::SW, Direction::SE];
+
+/// Colors beams from blue to yellow by the order of magnitude of their path count.
+struct PathCountPalette {
+    max_magnitude: f64,
+}
+
+impl PathCountPalette {
+    const EMPTY: Rgb = Rgb(24, 24, 32);
+    const START: Rgb = Rgb(60, 200, 80);
+    const FEW_PATHS: Rgb = Rgb(40, 80, 220);
+    const MANY_PATHS: Rgb = Rgb(250, 220, 40);
+
+    fn new(max_count: i64) -> PathCountPalette {
+        PathCountPalette { max_magnitude: (max_count.max(1) as f64).log10() }
+    }
+
+    fn color(&self, cell: Cell, count: i64) -> Rgb {
+        match cell {
+            Cell::Empty => Self::EMPTY,
+            Cell::Start => Self::START,
+            Cell::Splitter => Rgb::WHITE,
+            Cell::Beam if self.max_magnitude > 0.0 => {
+                let magnitude = (count.max(1) as f64).log10();
+                Self::FEW_PATHS.lerp(Self::MANY_PATHS, magnitude / self.max_magnitude)
+            }
+            Cell::Beam => Self::FEW_PATHS,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Cell {
+    Empty,
+    Start,
+    Splitter,
+    Beam,
+}
+
+impl Cell {
+    fn parse(ch: char) -> Result<Cell, String> {
+        match ch {
+            '.' => Ok(Cell::Empty),
+            'S' => Ok(Cell::Start),
+            '^' => Ok(Cell::Splitter),
+            '|' => Ok(Cell::Beam),
+            _ => Err(format!("expected '.', 'S', '^' or '|', got '{ch}'")),
+        }
+    }
+
+    fn as_char(&self) -> char {
+        match self {
+            Cell::Empty => '.',
+            Cell::Start => 'S',
+            Cell::Splitter => '^',
+            Cell::Beam => '|',
+        }
+    }
+}
+
+/// Propagates beams row by row calling `on_row` after each one.
+fn propagate_beams_fully<E>(
+    grid: &mut Grid<Cell>,
+    mut on_row: impl FnMut(&Grid<Cell>) -> Result<(), E>
+) -> Result<(), E> {
+    for j in 0..grid.height() {
+        for i in 0..grid.width() {
+            let from = Point2::new(i, j);
+            match grid.get(from) {
+                Some(Cell::Start) | Some(Cell::Beam) => {
+                    propagate_beam(grid, from);
+                }
+                Some(Cell::Splitter) => {
+                    if let Some(Cell::Beam) = grid.get(from + Direction::N) {
+                        let split_to: Vec<_> = grid.neighbors(from, SPLIT_BEAMS)
+                            .filter(|(_, cell)| **cell == Cell::Empty)
+                            .map(|(at, _)| at)
+                            .collect();
+                        for at in split_to {
+                            grid.set(at, Cell::Beam);
+                            propagate_beam(grid, at.into());
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        on_row(grid)?;
+    }
+    Ok(())
+}
+
+fn propagate_beam(grid: &mut Grid<Cell>, from: Point2) {
+    let next = from + Direction::S;
+    if let Some(Cell::Empty) = grid.get(next) {
+        grid.set(next, Cell::Beam);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+";
+
+    #[test]
+    fn test_example() {
+        let solution = Puzzle07 { write_output: false };
+        let input = solution.parse(EXAMPLE).unwrap();
+        assert_eq!(solution.part1(&input).unwrap(), 21);
+        assert_eq!(solution.part2(&input).unwrap(), 40);
+    }
+}
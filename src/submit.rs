@@ -0,0 +1,76 @@
+use crate::days::Day;
+use core_lib::{
+    AnswerRegistry, AocClient, AocResult, SubmissionCheck, SubmissionHistory, Verdict,
+    data_paths, input_fingerprint, read_input_file,
+};
+
+/// Submission history file in the data directory (verdicts are specific to the account).
+const SUBMISSIONS_FILE: &str = "submissions.json";
+
+/// Runs the day part and submits its answer unless the history shows it is wrong.
+///
+/// Correct answer is recorded into the registry. Returns whether the answer was accepted.
+pub fn submit_day(
+    day: &Day,
+    part: u32,
+    client: &AocClient,
+    registry: &mut AnswerRegistry
+) -> AocResult<bool> {
+    let input_path = client.fetch_input(day.day)?;
+    let input = read_input_file(&input_path)?;
+    let run = (day.solution)(false).run(&input, &[part])
+        .map_err(|err| err.with_file(&input_path))?;
+    let Some(answer) = run.parts.into_iter().find_map(|result| result.answer) else {
+        println!("Day {} part {part} is unsolved, nothing to submit", day.day);
+        return Ok(false);
+    };
+    println!("Day {} part {part}: {answer}", day.day);
+
+    let mut history = SubmissionHistory::load(data_paths().get(SUBMISSIONS_FILE))?;
+    match history.check(day.day, part, &answer) {
+        SubmissionCheck::Allowed => {}
+        SubmissionCheck::AlreadyCorrect { answer: correct } => {
+            if correct != answer {
+                println!("Answer differs from the accepted one: {correct}");
+                return Ok(false);
+            }
+            println!("Already accepted, not submitting again");
+            return Ok(true);
+        }
+        SubmissionCheck::KnownWrong { verdict } => {
+            println!("Answer was already rejected ({verdict}), not submitting");
+            return Ok(false);
+        }
+        SubmissionCheck::OutOfBounds { low, high } => {
+            let low = low.map(|v| format!("> {v}"));
+            let high = high.map(|v| format!("< {v}"));
+            let bounds: Vec<_> = [low, high].into_iter().flatten().collect();
+            println!("Answer is out of known bounds ({}), not submitting", bounds.join(", "));
+            return Ok(false);
+        }
+    }
+
+    let verdict = client.submit_answer(day.day, part, &answer)?;
+    if history.record(day.day, part, &answer, &verdict) {
+        history.save()?;
+    }
+
+    match verdict {
+        Verdict::Correct => {
+            println!("Correct answer!");
+            registry.record(day.day, part, &input_fingerprint(&input), &answer);
+            registry.save()?;
+            return Ok(true);
+        }
+        Verdict::TooHigh => println!("Wrong answer: too high"),
+        Verdict::TooLow => println!("Wrong answer: too low"),
+        Verdict::Wrong => println!("Wrong answer"),
+        Verdict::RateLimited { wait } => match wait {
+            Some(wait) => println!("Answered too recently, wait {wait:?} before retrying"),
+            None => println!("Answered too recently, wait before retrying"),
+        },
+        Verdict::WrongLevel => println!("Part is already solved or not available yet"),
+        Verdict::Unknown(text) => println!("Unrecognized response:\n{text}"),
+    }
+    return Ok(false);
+}
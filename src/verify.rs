@@ -0,0 +1,96 @@
+use crate::days::Day;
+use core_lib::{AnswerCheck, AnswerRegistry, AocError, AocResult, data_paths, input_fingerprint, read_input_file};
+use std::io::{IsTerminal, Write, stdin, stdout};
+
+#[derive(Default)]
+struct VerifySummary {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+    skipped: usize,
+}
+
+/// Runs every selected day and compares answers with the registry.
+///
+/// Missing answers are recorded if `record` is set, otherwise the user
+/// is asked to record each one when running in a terminal.
+pub fn verify_days(days: &[&Day], registry: &mut AnswerRegistry, record: bool) -> AocResult<bool> {
+    let interactive = !record && stdin().is_terminal();
+    let mut summary = VerifySummary::default();
+    let mut recorded = 0;
+
+    for day in days {
+        let input_path = match data_paths().find(day.input_file()) {
+            Ok(path) => path,
+            Err(err) => {
+                println!("Day {}: skipped\n{err}", day.day);
+                summary.skipped += 1;
+                continue;
+            }
+        };
+        let input = read_input_file(&input_path)?;
+        let fingerprint = input_fingerprint(&input);
+
+        let run = match (day.solution)(false).run(&input, &[1, 2]).map_err(|err| err.with_file(&input_path)) {
+            Ok(run) => run,
+            Err(err) => {
+                println!("Day {}: FAIL\n  error: {err}", day.day);
+                summary.failed += 1;
+                continue;
+            }
+        };
+
+        for result in run.parts {
+            let label = format!("Day {} part {}", day.day, result.part);
+            let Some(answer) = result.answer else {
+                println!("{label}: unsolved");
+                summary.skipped += 1;
+                continue;
+            };
+
+            match registry.check(day.day, result.part, &fingerprint, &answer) {
+                AnswerCheck::Pass => {
+                    println!("{label}: pass ({answer})");
+                    summary.passed += 1;
+                }
+                AnswerCheck::Fail { expected } => {
+                    println!("{label}: FAIL");
+                    println!("  - expected: {expected}");
+                    println!("  + actual:   {answer}");
+                    summary.failed += 1;
+                }
+                AnswerCheck::Missing => {
+                    println!("{label}: missing ({answer})");
+                    summary.missing += 1;
+                    if record || (interactive && confirm("  Record this answer?")?) {
+                        registry.record(day.day, result.part, &fingerprint, &answer);
+                        recorded += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    println!(
+        "Passed: {}, failed: {}, missing: {}, skipped: {}",
+        summary.passed, summary.failed, summary.missing, summary.skipped
+    );
+
+    if recorded > 0 {
+        registry.save()?;
+        let answer_word = if recorded == 1 { "answer" } else { "answers" };
+        println!("Recorded {recorded} new {answer_word}");
+    } else if summary.missing > 0 && !interactive {
+        println!("Run with --record to save missing answers");
+    }
+
+    Ok(summary.failed == 0)
+}
+
+fn confirm(question: &str) -> AocResult<bool> {
+    print!("{question} [y/N] ");
+    stdout().flush().map_err(AocError::Stream)?;
+    let mut line = String::new();
+    stdin().read_line(&mut line).map_err(AocError::Stream)?;
+    Ok(matches!(line.trim(), "y" | "Y" | "yes"))
+}